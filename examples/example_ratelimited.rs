@@ -4,6 +4,7 @@ use std::{env, sync::Arc, time::Duration};
 
 use bunqers::client::Client;
 use bunqers::client_rate_limited::ClientRateLimited;
+use bunqers::types::{Amount, Currency, MonetaryAccountId};
 use ritlers::async_rt::RateLimiter;
 
 #[tokio::main]
@@ -11,14 +12,17 @@ async fn main() {
 	let mut args = env::args().skip(1);
 	let bunq_api_key = args.next().expect("No API key passed as parameter");
 
-	let api_base_url = "https://api.bunq.com/v1".into();
-	let app_name = "example-ratelimited".into();
+	let api_base_url = bunqers::PRODUCTION_API_URL;
+	let app_name = "example-ratelimited";
 
 	// Install the device once and persist the resulting InstallationContext.
 	// On subsequent runs, load it from disk and skip this step.
-	let installation =
-		bunqers::install_device(bunq_api_key, api_base_url, app_name, "my-device".into()).await;
-	let client: Client = bunqers::create_client(installation, None).await;
+	let installation = bunqers::install_device(bunq_api_key, api_base_url, app_name, "my-device")
+		.await
+		.expect("Failed to install device");
+	let client: Client = bunqers::create_client(installation, None)
+		.await
+		.expect("Failed to create client");
 
 	// Wrap the client in a rate-limited shell.
 	// Bunq allows 3 GET and 1 POST per second by default.
@@ -62,13 +66,17 @@ async fn main() {
 	// Create a payment request — uses ratelimiter_post.
 	client_rl
 		.create_payment_request_ratelimited(
-			12345,
-			"10.00".parse().unwrap(),
+			MonetaryAccountId(12345),
+			Amount::new("10.00".parse().unwrap(), Currency::Eur),
 			"Example payment".into(),
 			"https://example.com/redirect".into(),
 			|response| async move {
-				let created = response.into_result().expect("API returned an error");
-				println!("Created payment request with id: {}", created.id.id);
+				let created = response
+					.unwrap()
+					.expect("Payment request was rejected before sending")
+					.into_result()
+					.expect("API returned an error");
+				println!("Created payment request with id: {}", created.id);
 			},
 		)
 		.await;
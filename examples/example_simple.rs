@@ -1,6 +1,6 @@
 //! Minimal example: install a device, create a session, and fetch user data.
 
-use std::{env, time::Duration};
+use std::env;
 
 use bunqers::client_builder::ClientBuilder;
 
@@ -10,23 +10,11 @@ async fn main() -> Result<(), std::io::Error> {
 	let bunq_api_key = args.next().expect("No API key passed as parameter");
 	println!("Entered API key: {bunq_api_key}");
 
-	let api_base_url = format!("https://api.bunq.com/v1");
-
-	let client = ClientBuilder::new_without_key(api_base_url, "example-app-name".into())
+	let client = ClientBuilder::production("example-app-name")
 		.expect("Failed to create private key")
-		.install_device()
-		.await
-		.expect("Failed to install device")
-		.register_device(bunq_api_key, "my-test-device")
+		.authenticate(bunq_api_key, "my-test-device")
 		.await
-		.expect("Failed to register device")
-		.create_session()
-		.await
-		.expect("Failed to create session")
-		.build();
-
-	// Cooldown just to be sure
-	std::thread::sleep(Duration::from_secs(3));
+		.expect("Failed to authenticate");
 
 	println!(
 		"Hello, {}!",
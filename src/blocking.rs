@@ -0,0 +1,631 @@
+//! A blocking client for CLIs and scripts that don't use an async runtime.
+//!
+//! [`Client`] offers the same endpoints as the async
+//! [`Client`](crate::client::Client), but each call blocks until Bunq has
+//! responded. Like `reqwest::blocking`, it drives the async client on a
+//! runtime of its own, so it must not be used from within an async context:
+//! using it on a thread that already runs a Tokio runtime, e.g. inside
+//! `#[tokio::main]`, panics with "Cannot start a runtime from within a
+//! runtime". Use the async client there, or move the blocking calls to
+//! [`tokio::task::spawn_blocking`].
+//!
+//! It is deliberately not built on `reqwest::blocking`: that would mean a
+//! second copy of the signing, retry and session renewal logic in
+//! [`Messenger`](crate::messenger::Messenger), and `reqwest::blocking` runs a
+//! Tokio runtime on a background thread itself, so it would not drop the
+//! dependency either.
+//!
+//! ```rust,no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let installation: bunqers::InstallationContext = todo!();
+//! let client = bunqers::blocking::Client::create(installation, None)?;
+//!
+//! let user = client.get_user().into_result()?;
+//! println!("Hello, {}!", user.user_person.display_name);
+//! # Ok(())
+//! # }
+//! ```
+
+#[cfg(feature = "polling")]
+use std::time::Duration;
+
+use chrono::NaiveDate;
+use tokio::runtime::Runtime;
+
+#[cfg(feature = "polling")]
+use crate::client::WaitForPaymentError;
+
+use crate::{
+	InstallationContext,
+	client::SessionContext,
+	client_builder::{AuthenticationError, BuildError, ClientBuilder, FlowError, Registered},
+	messenger::{BunqResponse, MessageError},
+	types::*,
+	validation::ValidationError,
+};
+
+/// Generates methods that call the async method of the same name on the
+/// inner client and block until it has finished. Each entry is written like
+/// the signature of the async method without `&self`.
+macro_rules! blocking_methods {
+	($(
+		$(#[$attr:meta])*
+		fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;
+	)*) => {
+		$(
+			#[doc = concat!(
+				"See [`Client::", stringify!($name), "`](crate::client::Client::",
+				stringify!($name), ")."
+			)]
+			$(#[$attr])*
+			pub fn $name(&self, $($arg: $ty),*) -> $ret {
+				self.runtime.block_on(self.inner.$name($($arg),*))
+			}
+		)*
+	};
+}
+
+/// A Bunq API client whose requests block the calling thread.
+///
+/// # Panics
+///
+/// All methods that send requests panic when called from within an async
+/// context, see the [module documentation](self).
+///
+/// Use [`inner`](Client::inner) and [`inner_mut`](Client::inner_mut) for the
+/// settings of the underlying async client, e.g.
+/// [`set_auto_reauthenticate`](crate::client::Client::set_auto_reauthenticate).
+pub struct Client {
+	inner: crate::client::Client,
+	runtime: Runtime,
+}
+
+impl Client {
+	/// Wraps an async `client`.
+	///
+	/// # Panics
+	///
+	/// Panics if the runtime for the requests cannot be created.
+	pub fn new(client: crate::client::Client) -> Self {
+		Self {
+			inner: client,
+			runtime: new_runtime(),
+		}
+	}
+
+	/// Blocking version of [`create_client`](crate::create_client).
+	///
+	/// # Panics
+	///
+	/// Panics if the runtime for the requests cannot be created.
+	pub fn create(
+		installation_context: InstallationContext,
+		session_token: Option<String>,
+	) -> Result<Self, FlowError> {
+		let runtime = new_runtime();
+		let inner = runtime.block_on(crate::create_client(installation_context, session_token))?;
+		Ok(Self { inner, runtime })
+	}
+
+	/// Blocking version of [`ClientBuilder::authenticate`].
+	///
+	/// # Panics
+	///
+	/// Panics if the runtime for the requests cannot be created.
+	// Same error type as the async client, which clippy does not lint.
+	#[allow(clippy::result_large_err)]
+	pub fn authenticate(
+		builder: ClientBuilder<()>,
+		bunq_api_key: impl Into<String>,
+		device_description: impl Into<String>,
+	) -> Result<Self, AuthenticationError> {
+		let runtime = new_runtime();
+		let inner = runtime.block_on(builder.authenticate(bunq_api_key, device_description))?;
+		Ok(Self { inner, runtime })
+	}
+
+	/// Returns the underlying async client.
+	pub fn inner(&self) -> &crate::client::Client {
+		&self.inner
+	}
+
+	/// Returns the underlying async client mutably.
+	pub fn inner_mut(&mut self) -> &mut crate::client::Client {
+		&mut self.inner
+	}
+
+	/// Unwraps the underlying async client.
+	pub fn into_inner(self) -> crate::client::Client {
+		self.inner
+	}
+
+	/// See [`Client::ensure_session`](crate::client::Client::ensure_session).
+	// Same error type as the async client, which clippy does not lint.
+	#[allow(clippy::result_large_err)]
+	pub fn ensure_session(&mut self) -> Result<(), BuildError<Registered>> {
+		self.runtime.block_on(self.inner.ensure_session())
+	}
+
+	/// See [`Client::rotate_api_key`](crate::client::Client::rotate_api_key).
+	// Same error type as the async client, which clippy does not lint.
+	#[allow(clippy::result_large_err)]
+	pub fn rotate_api_key(
+		&mut self,
+		new_api_key: impl Into<String>,
+		device_description: impl Into<String>,
+	) -> Result<SessionContext, AuthenticationError> {
+		self.runtime
+			.block_on(self.inner.rotate_api_key(new_api_key, device_description))
+	}
+
+	/// See [`Client::logout`](crate::client::Client::logout).
+	pub fn logout(self) -> Result<BunqResponse<Empty>, MessageError> {
+		let Client { inner, runtime } = self;
+		runtime.block_on(inner.logout())
+	}
+
+	blocking_methods! {
+		fn get_installations() -> BunqResponse<Multiple<InstallationIdWrapper>>;
+
+		fn get_installation(installation_id: u32) -> BunqResponse<Single<InstallationIdWrapper>>;
+
+		fn get_server_public_key(
+			installation_id: u32,
+		) -> BunqResponse<Single<ServerPublicKeyWrapper>>;
+
+		fn get_user() -> BunqResponse<Single<User>>;
+
+		fn update_user_person_address(
+			changes: AlterUserAddress,
+		) -> BunqResponse<Single<AlterUserResponseWrapper>>;
+
+		fn get_user_company() -> BunqResponse<Single<UserCompanyWrapper>>;
+
+		fn update_user_company_address(
+			changes: AlterUserAddress,
+		) -> BunqResponse<Single<AlterUserResponseWrapper>>;
+
+		fn get_monetary_accounts() -> BunqResponse<Multiple<MonetaryAccountBankWrapper>>;
+
+		fn get_monetary_account(
+			bank_account_id: MonetaryAccountId,
+		) -> BunqResponse<Single<MonetaryAccountBankWrapper>>;
+
+		fn get_payment_request(
+			monetary_account_id: MonetaryAccountId,
+			payment_request_id: BunqMeTabId,
+		) -> BunqResponse<Single<BunqMeTabWrapper>>;
+
+		fn create_payment_request(
+			monetary_account_id: MonetaryAccountId,
+			amount: Amount,
+			description: String,
+			redirect_url: String,
+		) -> Result<BunqResponse<BunqMeTabHandle>, ValidationError>;
+
+		fn create_payment_request_with_options(
+			monetary_account_id: MonetaryAccountId,
+			tab: CreateBunqMeTab,
+		) -> BunqResponse<BunqMeTabHandle>;
+
+		#[cfg(feature = "polling")]
+		fn wait_for_payment(
+			monetary_account_id: MonetaryAccountId,
+			payment_request_id: BunqMeTabId,
+			poll_interval: Duration,
+			timeout: Duration,
+		) -> Result<PaymentRequestOutcome, WaitForPaymentError>;
+
+		fn update_payment_request(
+			monetary_account_id: MonetaryAccountId,
+			payment_request_id: BunqMeTabId,
+			status: Option<BunqMeTabStatus>,
+			note: Option<String>,
+		) -> BunqResponse<Single<AlterBunqMeTabResponseWrapper>>;
+
+		fn close_payment_request(
+			monetary_account_id: MonetaryAccountId,
+			payment_request_id: BunqMeTabId,
+		) -> BunqResponse<Single<AlterBunqMeTabResponseWrapper>>;
+
+		fn reopen_payment_request(
+			monetary_account_id: MonetaryAccountId,
+			payment_request_id: BunqMeTabId,
+		) -> BunqResponse<Single<AlterBunqMeTabResponseWrapper>>;
+
+		fn get_cash_registers(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<CashRegisterWrapper>>;
+
+		fn get_cash_register(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+		) -> BunqResponse<Single<CashRegisterWrapper>>;
+
+		fn create_tab_usage_single(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab: CreateTabUsageSingle,
+		) -> BunqResponse<Single<CreateTabResponseWrapper>>;
+
+		fn update_tab_usage_single(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab_uuid: &str,
+			changes: AlterTabUsageSingle,
+		) -> BunqResponse<Single<CreateTabResponseWrapper>>;
+
+		fn get_tab_usage_single(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab_uuid: &str,
+		) -> BunqResponse<Single<TabUsageSingleWrapper>>;
+
+		fn get_tab_usage_singles(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+		) -> BunqResponse<Multiple<TabUsageSingleWrapper>>;
+
+		fn create_tab_usage_multiple(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab: CreateTabUsageMultiple,
+		) -> BunqResponse<Single<CreateTabResponseWrapper>>;
+
+		fn update_tab_usage_multiple(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab_uuid: &str,
+			changes: AlterTabUsageMultiple,
+		) -> BunqResponse<Single<CreateTabResponseWrapper>>;
+
+		fn get_tab_usage_multiple(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab_uuid: &str,
+		) -> BunqResponse<Single<TabUsageMultipleWrapper>>;
+
+		fn get_tab_usage_multiples(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+		) -> BunqResponse<Multiple<TabUsageMultipleWrapper>>;
+
+		fn create_tab_item(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab_uuid: &str,
+			item: CreateTabItemShop,
+		) -> BunqResponse<Single<CreateTabItemShopResponseWrapper>>;
+
+		fn get_tab_items(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab_uuid: &str,
+		) -> BunqResponse<Multiple<TabItemShopWrapper>>;
+
+		fn get_tab_item(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab_uuid: &str,
+			tab_item_id: u32,
+		) -> BunqResponse<Single<TabItemShopWrapper>>;
+
+		fn update_tab_item(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab_uuid: &str,
+			tab_item_id: u32,
+			changes: AlterTabItemShop,
+		) -> BunqResponse<Single<CreateTabItemShopResponseWrapper>>;
+
+		fn delete_tab_item(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab_uuid: &str,
+			tab_item_id: u32,
+		) -> BunqResponse<Empty>;
+
+		fn get_tab_qr_code(
+			monetary_account_id: MonetaryAccountId,
+			cash_register_id: u32,
+			tab_uuid: &str,
+		) -> BunqResponse<Vec<u8>>;
+
+		fn get_draft_share_invite_qr_code(draft_share_invite_id: u32) -> BunqResponse<Vec<u8>>;
+
+		fn create_ideal_merchant_transaction(
+			monetary_account_id: MonetaryAccountId,
+			transaction: CreateIdealMerchantTransaction,
+		) -> BunqResponse<Single<CreateIdealMerchantTransactionResponseWrapper>>;
+
+		fn get_ideal_merchant_transaction(
+			monetary_account_id: MonetaryAccountId,
+			ideal_merchant_transaction_id: u32,
+		) -> BunqResponse<Single<IdealMerchantTransactionWrapper>>;
+
+		fn get_ideal_merchant_transactions(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<IdealMerchantTransactionWrapper>>;
+
+		fn create_token_qr_request_ideal(
+			token: String,
+		) -> BunqResponse<Single<TokenQrRequestIdealWrapper>>;
+
+		fn create_transferwise_quote(
+			quote: CreateTransferwiseQuote,
+		) -> BunqResponse<Single<CreateTransferwiseResponseWrapper>>;
+
+		fn get_transferwise_quote(
+			transferwise_quote_id: u32,
+		) -> BunqResponse<Single<TransferwiseQuoteWrapper>>;
+
+		fn create_transferwise_transfer(
+			transferwise_quote_id: u32,
+			transfer: CreateTransferwiseTransfer,
+		) -> BunqResponse<Single<CreateTransferwiseResponseWrapper>>;
+
+		fn get_transferwise_transfer(
+			transferwise_quote_id: u32,
+			transferwise_transfer_id: u32,
+		) -> BunqResponse<Single<TransferwiseTransferWrapper>>;
+
+		fn get_transferwise_transfers(
+			transferwise_quote_id: u32,
+		) -> BunqResponse<Multiple<TransferwiseTransferWrapper>>;
+
+		fn create_payment_auto_allocate(
+			monetary_account_id: MonetaryAccountId,
+			rule: CreatePaymentAutoAllocate,
+		) -> BunqResponse<Single<CreatePaymentAutoAllocateResponseWrapper>>;
+
+		fn get_payment_auto_allocates(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<PaymentAutoAllocateWrapper>>;
+
+		fn get_payment_auto_allocate(
+			monetary_account_id: MonetaryAccountId,
+			payment_auto_allocate_id: u32,
+		) -> BunqResponse<Single<PaymentAutoAllocateWrapper>>;
+
+		fn update_payment_auto_allocate(
+			monetary_account_id: MonetaryAccountId,
+			payment_auto_allocate_id: u32,
+			changes: AlterPaymentAutoAllocate,
+		) -> BunqResponse<Single<CreatePaymentAutoAllocateResponseWrapper>>;
+
+		fn delete_payment_auto_allocate(
+			monetary_account_id: MonetaryAccountId,
+			payment_auto_allocate_id: u32,
+		) -> BunqResponse<Empty>;
+
+		fn create_auto_save(
+			monetary_account_id: MonetaryAccountId,
+			rule: CreateAutoSave,
+		) -> BunqResponse<Single<CreateAutoSaveResponseWrapper>>;
+
+		fn get_auto_saves(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<AutoSaveWrapper>>;
+
+		fn update_auto_save(
+			monetary_account_id: MonetaryAccountId,
+			auto_save_id: u32,
+			changes: AlterAutoSave,
+		) -> BunqResponse<Single<CreateAutoSaveResponseWrapper>>;
+
+		fn get_switch_service_payment(
+			monetary_account_id: MonetaryAccountId,
+			switch_service_payment_id: u32,
+		) -> BunqResponse<Single<BankSwitchServicePaymentWrapper>>;
+
+		fn get_permitted_ips(
+			credential_password_ip_id: u32,
+		) -> BunqResponse<Multiple<PermittedIpWrapper>>;
+
+		fn get_permitted_ip(
+			credential_password_ip_id: u32,
+			permitted_ip_id: u32,
+		) -> BunqResponse<Single<PermittedIpWrapper>>;
+
+		fn create_permitted_ip(
+			credential_password_ip_id: u32,
+			permitted_ip: CreatePermittedIp,
+		) -> BunqResponse<Single<CreatePermittedIpResponseWrapper>>;
+
+		fn update_permitted_ip(
+			credential_password_ip_id: u32,
+			permitted_ip_id: u32,
+			status: PermittedIpStatus,
+		) -> BunqResponse<Single<CreatePermittedIpResponseWrapper>>;
+
+		fn get_credential_password_ips() -> BunqResponse<Multiple<CredentialPasswordIpWrapper>>;
+
+		fn get_credential_password_ip(
+			credential_password_ip_id: u32,
+		) -> BunqResponse<Single<CredentialPasswordIpWrapper>>;
+
+		fn get_payments(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<PaymentWrapper>>;
+
+		fn get_payments_page(
+			monetary_account_id: MonetaryAccountId,
+			cursor: &PageCursor,
+		) -> BunqResponse<Multiple<PaymentWrapper>>;
+
+		fn get_payment(
+			monetary_account_id: MonetaryAccountId,
+			payment_id: PaymentId,
+		) -> BunqResponse<Single<PaymentWrapper>>;
+
+		fn set_payment_category(
+			monetary_account_id: MonetaryAccountId,
+			payment_id: PaymentId,
+			category: String,
+		) -> BunqResponse<Single<AlterPaymentCategoryResponseWrapper>>;
+
+		fn get_insights(
+			date_from: NaiveDate,
+			date_to: NaiveDate,
+		) -> BunqResponse<Multiple<InsightWrapper>>;
+
+		fn get_reward_senders() -> BunqResponse<Multiple<RewardSenderWrapper>>;
+
+		fn get_reward_sender(reward_id: u32) -> BunqResponse<Single<RewardSenderWrapper>>;
+
+		fn get_reward_recipients() -> BunqResponse<Multiple<RewardRecipientWrapper>>;
+
+		fn get_reward_recipient(reward_id: u32) -> BunqResponse<Single<RewardRecipientWrapper>>;
+
+		fn create_currency_conversion_quote(
+			monetary_account_id: MonetaryAccountId,
+			quote: CreateCurrencyConversionQuote,
+		) -> BunqResponse<Single<CreateCurrencyConversionQuoteResponseWrapper>>;
+
+		fn get_currency_conversion_quote(
+			monetary_account_id: MonetaryAccountId,
+			currency_conversion_quote_id: u32,
+		) -> BunqResponse<Single<CurrencyConversionQuoteWrapper>>;
+
+		fn accept_currency_conversion_quote(
+			monetary_account_id: MonetaryAccountId,
+			currency_conversion_quote_id: u32,
+		) -> BunqResponse<Single<CreateCurrencyConversionQuoteResponseWrapper>>;
+
+		fn get_currency_conversions(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<CurrencyConversionWrapper>>;
+
+		fn get_currency_conversion(
+			monetary_account_id: MonetaryAccountId,
+			currency_conversion_id: u32,
+		) -> BunqResponse<Single<CurrencyConversionWrapper>>;
+
+		fn get_pinned_certificates() -> BunqResponse<Multiple<CertificatePinnedWrapper>>;
+
+		fn get_pinned_certificate(
+			certificate_pinned_id: u32,
+		) -> BunqResponse<Single<CertificatePinnedWrapper>>;
+
+		fn pin_certificate(
+			certificate_chain: Vec<String>,
+		) -> BunqResponse<Single<CreateCertificatePinnedResponseWrapper>>;
+
+		fn delete_pinned_certificate(certificate_pinned_id: u32) -> BunqResponse<Empty>;
+
+		fn get_schedules(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<ScheduleWrapper>>;
+
+		fn get_schedule(
+			monetary_account_id: MonetaryAccountId,
+			schedule_id: u32,
+		) -> BunqResponse<Single<ScheduleWrapper>>;
+
+		fn get_schedule_payment(
+			monetary_account_id: MonetaryAccountId,
+			schedule_payment_id: u32,
+		) -> BunqResponse<Single<SchedulePaymentWrapper>>;
+
+		fn update_schedule_payment(
+			monetary_account_id: MonetaryAccountId,
+			schedule_payment_id: u32,
+			schedule: Schedule,
+		) -> BunqResponse<Single<AlterSchedulePaymentResponseWrapper>>;
+
+		fn get_request_inquiries(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<RequestInquiryWrapper>>;
+
+		fn get_request_inquiry(
+			monetary_account_id: MonetaryAccountId,
+			request_inquiry_id: u32,
+		) -> BunqResponse<Single<RequestInquiryWrapper>>;
+
+		fn get_request_responses(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<RequestResponseWrapper>>;
+
+		fn get_request_response(
+			monetary_account_id: MonetaryAccountId,
+			request_response_id: u32,
+		) -> BunqResponse<Single<RequestResponseWrapper>>;
+
+		fn get_notification_filters_url() -> BunqResponse<Multiple<NotificationFilterUrlWrapper>>;
+
+		fn set_notification_filters_url(
+			notification_filters: Vec<NotificationFilterUrl>,
+		) -> BunqResponse<Empty>;
+
+		fn get_monetary_account_notification_filters_url(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<NotificationFilterUrlWrapper>>;
+
+		fn set_monetary_account_notification_filters_url(
+			monetary_account_id: MonetaryAccountId,
+			notification_filters: Vec<NotificationFilterUrl>,
+		) -> BunqResponse<Empty>;
+
+		fn get_notification_filters_push() -> BunqResponse<Multiple<NotificationFilterPushWrapper>>;
+
+		fn set_notification_filters_push(
+			notification_filters: Vec<NotificationFilterPush>,
+		) -> BunqResponse<Empty>;
+
+		fn get_mastercard_actions(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<MasterCardActionWrapper>>;
+
+		fn get_mastercard_action(
+			monetary_account_id: MonetaryAccountId,
+			mastercard_action_id: u32,
+		) -> BunqResponse<Single<MasterCardActionWrapper>>;
+
+		fn get_cards() -> BunqResponse<Multiple<CardWrapper>>;
+
+		fn get_card(card_id: u32) -> BunqResponse<Single<CardWrapper>>;
+
+		fn update_card(card_id: u32, update: &UpdateCard) -> BunqResponse<Single<CardWrapper>>;
+
+		fn create_customer_statement(
+			monetary_account_id: MonetaryAccountId,
+			export: CreateCustomerStatementExport,
+		) -> BunqResponse<Single<CreateCustomerStatementExportResponseWrapper>>;
+
+		fn get_customer_statements(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<CustomerStatementExportWrapper>>;
+
+		fn get_customer_statement(
+			monetary_account_id: MonetaryAccountId,
+			customer_statement_id: u32,
+		) -> BunqResponse<Single<CustomerStatementExportWrapper>>;
+
+		fn get_customer_statement_content(
+			monetary_account_id: MonetaryAccountId,
+			customer_statement_id: u32,
+		) -> BunqResponse<Vec<u8>>;
+
+		fn create_draft_payment(
+			monetary_account_id: MonetaryAccountId,
+			draft_payment: CreateDraftPayment,
+		) -> BunqResponse<Single<CreateDraftPaymentResponseWrapper>>;
+
+		fn get_draft_payments(
+			monetary_account_id: MonetaryAccountId,
+		) -> BunqResponse<Multiple<DraftPaymentWrapper>>;
+
+		fn get_draft_payment(
+			monetary_account_id: MonetaryAccountId,
+			draft_payment_id: u32,
+		) -> BunqResponse<Single<DraftPaymentWrapper>>;
+	}
+}
+
+/// Creates the single-threaded runtime a blocking client runs its requests on.
+fn new_runtime() -> Runtime {
+	tokio::runtime::Builder::new_current_thread()
+		.enable_all()
+		.build()
+		.expect("Failed to create runtime for the blocking client")
+}
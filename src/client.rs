@@ -1,33 +1,123 @@
-use openssl::pkey::{PKey, Private, Public};
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
 use reqwest::Method;
-use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
 
 use crate::{
-	client_builder::{ClientBuilder, Registered},
-	messenger::{ApiResponse, Messenger},
+	client_builder::{
+		AuthenticationError, BuildError, BuildErrorReason, ClientBuilder, Installed, Registered,
+		builder_messenger,
+	},
+	crypto::{PrivateKey, PublicKey},
+	deserialization::{deserialize_public_key, serialize_public_key},
+	messenger::{
+		BunqResponse, Geolocation, MessageError, Messenger, ParsingMode, RequestOptions,
+		RetryPolicy, SessionCredentials,
+	},
+	rate_limiter::RateLimiter,
 	types::*,
+	validation::ValidationError,
 };
 
 /// Credentials that are valid for the lifetime of a single Bunq session.
 ///
-/// A session is created by [`ClientBuilder::create_session`] and remains valid
-/// until it expires (default: 1 hour) or is explicitly closed. The session
-/// token is sent as the `X-Bunq-Client-Authentication` header on every
-/// subsequent request.
-#[derive(Clone)]
+/// A session is created by
+/// [`ClientBuilder::create_session`](crate::client_builder::ClientBuilder::create_session)
+/// and remains valid until it expires (default: 1 hour) or is explicitly
+/// closed. The session token is sent as the `X-Bunq-Client-Authentication`
+/// header on every subsequent request.
+///
+/// The context can be persisted with `serde`; Bunq's public key is written as
+/// a PEM string.
+#[derive(Serialize, Deserialize, Clone)]
 pub struct SessionContext {
 	/// Numeric user ID of the account that owns this session.
-	pub owner_id: u32,
+	pub owner_id: UserId,
 	/// Token for authenticating subsequent API requests.
 	pub session_token: String,
 	/// Device ID assigned during registration.
-	pub registered_device_id: u32,
+	pub registered_device_id: DeviceId,
 	/// Bunq API key used to create the session.
 	pub bunq_api_key: String,
 	/// Installation token from the `/installation` step; kept for re-auth.
 	pub installation_token: String,
 	/// Bunq's RSA public key used to verify response signatures.
-	pub bunq_public_key: PKey<Public>,
+	#[serde(
+		serialize_with = "serialize_public_key",
+		deserialize_with = "deserialize_public_key"
+	)]
+	pub bunq_public_key: PublicKey,
+	/// When the session was created; `None` if unknown, e.g. for a stored
+	/// session that was only validated.
+	#[serde(default)]
+	pub session_created_at: Option<DateTime<Utc>>,
+	/// Seconds the session stays valid after its creation, as reported by
+	/// Bunq when the session was created.
+	#[serde(default)]
+	pub session_timeout: Option<i32>,
+}
+
+impl SessionContext {
+	/// Returns a copy of this context that uses `session` instead.
+	fn with_session(&self, session: &Session) -> Self {
+		Self {
+			owner_id: session.user_person.id,
+			session_token: session.token.token.clone(),
+			session_created_at: Some(session.token.created.and_utc()),
+			session_timeout: Some(session.user_person.session_timeout),
+			..self.clone()
+		}
+	}
+
+	/// Returns when the session expires, if its lifetime is known.
+	pub fn session_expires_at(&self) -> Option<DateTime<Utc>> {
+		Some(self.session_created_at? + TimeDelta::seconds(self.session_timeout?.into()))
+	}
+}
+
+/// Shortest interval between two polls of [`Client::wait_for_payment`]. Bunq
+/// allows three `GET` requests per three seconds.
+#[cfg(feature = "polling")]
+pub const MIN_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Errors that can occur while waiting for a payment request to be settled.
+#[cfg(feature = "polling")]
+#[derive(Debug)]
+pub enum WaitForPaymentError {
+	/// The payment request was still open when the timeout passed.
+	TimedOut,
+	/// Bunq rejected the request for the payment request's state.
+	Api(crate::messenger::ApiErrorResponse),
+}
+
+#[cfg(feature = "polling")]
+impl std::fmt::Display for WaitForPaymentError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			WaitForPaymentError::TimedOut => write!(f, "timed out waiting for payment"),
+			WaitForPaymentError::Api(error) => {
+				write!(f, "failed to fetch payment request: {error}")
+			}
+		}
+	}
+}
+
+#[cfg(feature = "polling")]
+impl std::error::Error for WaitForPaymentError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			WaitForPaymentError::TimedOut => None,
+			WaitForPaymentError::Api(error) => Some(error),
+		}
+	}
+}
+
+/// Scales `interval` by a random factor between 0.9 and 1.1.
+#[cfg(feature = "keepalive")]
+fn jittered(interval: Duration) -> Duration {
+	interval.mul_f64(crate::messenger::random_factor(0.9, 1.1))
 }
 
 /// A ready-to-use Bunq API client with an active session.
@@ -35,76 +125,496 @@ pub struct SessionContext {
 /// Obtain a `Client` via [`crate::create_client`] or by driving
 /// [`crate::client_builder::ClientBuilder`] through its typestate chain.
 ///
-/// Every endpoint method returns [`ApiResponse<T>`]. Call
-/// [`.into_result()`](ApiResponse::into_result) on the response to convert it
-/// into a `Result`, or check [`.is_rate_limited()`](ApiResponse::is_rate_limited)
+/// Every endpoint method returns [`BunqResponse<T>`]. Call
+/// [`.into_result()`](BunqResponse::into_result) on the response to convert it
+/// into a `Result`, or check [`.is_rate_limited()`](BunqResponse::is_rate_limited)
 /// first when using the client without the rate-limiting wrapper.
+///
+/// A `Client` is cheap to clone and can be shared between tasks. Clones use
+/// the same connection pool and session, so one client can serve many
+/// concurrent requests without a mutex:
+///
+/// ```
+/// fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+/// assert_shareable::<bunqers::client::Client>();
+/// ```
+#[derive(Clone)]
 pub struct Client {
 	pub api_base_url: String,
 	pub app_name: String,
-	pub private_key: PKey<Private>,
+	pub private_key: PrivateKey,
 	pub messenger: Messenger,
 	pub context: SessionContext,
 }
 
 impl Client {
+	/// Creates a client from a stored session without contacting Bunq.
+	///
+	/// Unlike [`ClientBuilder::check_session`](crate::client_builder::ClientBuilder::check_session),
+	/// the session token is not validated up front; an expired session only
+	/// shows up as an error on the first request. Combine with
+	/// [`set_auto_reauthenticate`](Client::set_auto_reauthenticate) to renew
+	/// it transparently instead. Settings such as the geolocation start at the
+	/// same defaults as for a client built with [`ClientBuilder`].
+	pub fn from_session_context(
+		context: SessionContext,
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+		private_key: PrivateKey,
+	) -> Self {
+		let api_base_url: String = api_base_url.into();
+		let app_name: String = app_name.into();
+
+		let mut messenger = builder_messenger(
+			api_base_url.clone(),
+			app_name.clone(),
+			private_key.clone(),
+			Some(context.bunq_public_key.clone()),
+			Some(context.session_token.clone()),
+		);
+		messenger.set_session_lifetime(context.session_created_at, context.session_timeout);
+
+		Self {
+			api_base_url,
+			app_name,
+			private_key,
+			messenger,
+			context,
+		}
+	}
+
 	/// Verifies that the current session is still valid and, if not, creates a
-	/// new one.
-	///
-	/// Returns `Ok(Client)` with a guaranteed-valid session. Returns
-	/// `Err(Registered)` if session creation itself fails (e.g. the device
-	/// registration was revoked), giving back the registration context so the
-	/// caller can decide how to proceed.
-	pub async fn ensure_session(self) -> Result<Self, Registered> {
-		// Reuse the ClientBuilder logic to verify the session.
-		let unchecked_session = ClientBuilder::from_unchecked_session(
-			self.context.into(),
+	/// new one in place.
+	///
+	/// All settings of the client, such as the parsing mode and the HTTP
+	/// client, are kept. Returns an error if session creation itself fails
+	/// (e.g. the device registration was revoked); the error's context holds
+	/// the registration so the caller can decide how to proceed, and the
+	/// client keeps its old session.
+	pub async fn ensure_session(&mut self) -> Result<(), BuildError<Registered>> {
+		let response: Result<BunqResponse<Single<User>>, _> =
+			self.messenger.send(Method::GET, "user", None).await;
+		if let Ok(Ok(_)) = response.map(BunqResponse::into_result) {
+			return Ok(());
+		}
+
+		self.renew_session().await
+	}
+
+	/// Creates a new session for the registration of this client and switches
+	/// to it.
+	async fn renew_session(&mut self) -> Result<(), BuildError<Registered>> {
+		let registration = || Registered {
+			registered_device_id: self.context.registered_device_id,
+			bunq_api_key: self.context.bunq_api_key.clone(),
+			installation_token: self.context.installation_token.clone(),
+			bunq_public_key: self.context.bunq_public_key.clone(),
+		};
+		let session = self
+			.messenger
+			.create_session(&self.session_credentials())
+			.await
+			.map_err(|error| BuildError {
+				reason: BuildErrorReason::BunqInvalidResponse(error),
+				context: registration(),
+			})?
+			.into_result()
+			.map_err(|error| BuildError {
+				reason: BuildErrorReason::BunqResponseApiError(error),
+				context: registration(),
+			})?;
+
+		self.messenger.switch_session(&session);
+		self.context = self.context.with_session(&session);
+		Ok(())
+	}
+
+	/// Keeps the session warm by sending a cheap authenticated request
+	/// (`GET /user`) about every `interval`.
+	///
+	/// Each wait is randomly stretched or shortened by up to 10%, so that
+	/// several clients started together do not hit the API at the same time.
+	/// Failed requests are ignored; the next regular request reports the
+	/// problem. The returned future never completes: drop it to stop.
+	///
+	/// ```no_run
+	/// # async fn serve(_client: &bunqers::client::Client) {}
+	/// # async fn example(client: bunqers::client::Client) {
+	/// use std::time::Duration;
+	///
+	/// tokio::select! {
+	///     _ = client.keep_alive(Duration::from_secs(300)) => {}
+	///     _ = serve(&client) => {}
+	/// }
+	/// # }
+	/// ```
+	#[cfg(feature = "keepalive")]
+	pub async fn keep_alive(&self, interval: Duration) {
+		loop {
+			tokio::time::sleep(jittered(interval)).await;
+			let _: Result<BunqResponse<Single<User>>, _> =
+				self.messenger.send(Method::GET, "user", None).await;
+		}
+	}
+
+	/// Calls `callback` with the new session context whenever the client
+	/// switches to a new session, so it can be persisted right away.
+	///
+	/// This covers [`ensure_session`](Client::ensure_session) as well as
+	/// automatic renewal. Replaces any previous callback.
+	///
+	/// ```no_run
+	/// # fn example(client: &mut bunqers::client::Client) {
+	/// use bunqers::session_state::SessionState;
+	///
+	/// let private_key = client.private_key.clone();
+	/// client.on_session_refreshed(move |context| {
+	///     SessionState::from_session(context, &private_key)
+	///         .save("context.json")
+	///         .expect("Failed to store context file");
+	/// });
+	/// # }
+	/// ```
+	pub fn on_session_refreshed(
+		&mut self,
+		callback: impl Fn(&SessionContext) + Send + Sync + 'static,
+	) {
+		let context = self.context.clone();
+		self.messenger
+			.set_session_observer(Some(Arc::new(move |session| {
+				callback(&context.with_session(session))
+			})));
+	}
+
+	/// Switches the client to a new API key.
+	///
+	/// Registers this client's installation for `new_api_key`, creates a
+	/// session with it and checks that the session works, then switches to it
+	/// and returns the new context, e.g. to persist it. If any step fails the
+	/// client keeps using its current API key and session, so a rotation can
+	/// be retried or abandoned safely.
+	///
+	/// The device registered for the old API key is left as it is. A callback
+	/// set with [`on_session_refreshed`](Client::on_session_refreshed) keeps
+	/// reporting the old registration; set it again after rotating.
+	pub async fn rotate_api_key(
+		&mut self,
+		new_api_key: impl Into<String>,
+		device_description: impl Into<String>,
+	) -> Result<SessionContext, AuthenticationError> {
+		let installation = Installed {
+			installation_token: self.context.installation_token.clone(),
+			bunq_public_key: self.context.bunq_public_key.clone(),
+		};
+		let context = ClientBuilder::from_installation(
+			installation,
 			self.api_base_url.clone(),
 			self.app_name.clone(),
 			self.private_key.clone(),
-		);
+		)
+		.with_http_client(self.messenger.http_client().clone())
+		.register_device(new_api_key, device_description)
+		.await
+		.map_err(AuthenticationError::Register)?
+		.create_session()
+		.await
+		.map_err(AuthenticationError::CreateSession)?
+		.context;
 
-		match unchecked_session.check_session().await {
-			Ok(checked_session) => {
-				return Ok(checked_session.build());
-			}
-			Err(error) => {
-				// Session token is invalid; create a new session from the
-				// existing registration.
-				let new_session_builder = ClientBuilder::from_registration(
-					error.context.into(),
-					self.api_base_url,
-					self.app_name,
-					self.private_key,
-				);
-				match new_session_builder.create_session().await {
-					Ok(checked_session) => {
-						return Ok(checked_session.build());
-					}
-					Err(error) => Err(error.context),
-				}
-			}
+		// Check the new session before switching, so a failure leaves the
+		// client untouched.
+		let response: Result<BunqResponse<Single<User>>, _> = self
+			.messenger
+			.send_verified(
+				Method::GET,
+				"user",
+				None,
+				Some(context.session_token.clone()),
+				&RequestOptions::default(),
+			)
+			.await;
+		let reason = match response.map(BunqResponse::into_result) {
+			Ok(Ok(_)) => None,
+			Ok(Err(error)) => Some(BuildErrorReason::BunqResponseApiError(error)),
+			Err(error) => Some(BuildErrorReason::BunqInvalidResponse(error)),
+		};
+		if let Some(reason) = reason {
+			return Err(AuthenticationError::CheckSession(BuildError {
+				reason,
+				context: context.into(),
+			}));
+		}
+
+		self.messenger
+			.set_authentication_token(Some(context.session_token.clone()));
+		self.messenger
+			.set_session_lifetime(context.session_created_at, context.session_timeout);
+		self.context = context.clone();
+		if self.messenger.session_credentials().is_some() {
+			self.messenger
+				.set_session_credentials(Some(self.session_credentials()));
+		}
+		Ok(context)
+	}
+
+	/// Closes the session at Bunq and wipes the credentials this client holds.
+	///
+	/// The session token is removed from the messenger and the token, API key
+	/// and installation token of this client are overwritten with zeros before
+	/// they are freed. The credentials are wiped even if Bunq rejects the
+	/// request or it cannot be sent; the returned response tells whether the
+	/// session was closed remotely.
+	///
+	/// Clones of this client share the session: they stop sending its token
+	/// and no longer create new sessions on their own, even with
+	/// [`set_auto_reauthenticate`](Client::set_auto_reauthenticate) enabled.
+	/// Their own copies of the credentials are not wiped, so an explicit
+	/// [`ensure_session`](Client::ensure_session) on a clone still creates a
+	/// new session.
+	///
+	/// Bunq API: `DELETE /session/{sessionId}`
+	pub async fn logout(mut self) -> Result<BunqResponse<Empty>, MessageError> {
+		// Bunq closes the session the request is authenticated with; the ID in
+		// the path is not checked.
+		let response = self.messenger.send(Method::DELETE, "session/0", None).await;
+
+		self.messenger.clear_authentication_token();
+		self.context.session_token.zeroize();
+		self.context.bunq_api_key.zeroize();
+		self.context.installation_token.zeroize();
+		response
+	}
+
+	/// Sets whether a new session is created automatically when Bunq rejects
+	/// the current one. Off by default.
+	///
+	/// When enabled, a request that fails because the session is missing or
+	/// expired is retried once with a new session created from this client's
+	/// device registration. The new token is not written back to
+	/// [`context`](Client::context); use [`session_context`](Client::session_context)
+	/// to persist the current session.
+	pub fn set_auto_reauthenticate(&mut self, enabled: bool) {
+		self.messenger
+			.set_session_credentials(Some(self.session_credentials()));
+		self.messenger.set_retry_rejected_sessions(enabled);
+	}
+
+	/// Sets how long before its expiry the session is renewed, or disables
+	/// proactive renewal with `None`. Off by default.
+	///
+	/// The expiry is derived from [`SessionContext::session_expires_at`]. A
+	/// session whose lifetime is unknown, such as one restored with
+	/// [`ClientBuilder::check_session`](crate::client_builder::ClientBuilder::check_session),
+	/// is only renewed once it is rejected (see
+	/// [`set_auto_reauthenticate`](Client::set_auto_reauthenticate)).
+	pub fn set_session_renewal_margin(&mut self, margin: Option<Duration>) {
+		self.messenger
+			.set_session_credentials(Some(self.session_credentials()));
+		self.messenger.set_session_renewal_margin(margin);
+	}
+
+	/// Returns the session context with the token the client currently uses,
+	/// including sessions created by automatic renewal.
+	pub fn session_context(&self) -> SessionContext {
+		let mut context = self.context.clone();
+		if let Some(session_token) = self.messenger.authentication_token() {
+			context.session_token = session_token;
+			context.session_created_at = self.messenger.session_created_at();
+			context.session_timeout = self.messenger.session_timeout();
 		}
+		context
+	}
+
+	/// Returns the credentials the messenger needs to create new sessions.
+	fn session_credentials(&self) -> SessionCredentials {
+		SessionCredentials {
+			installation_token: self.context.installation_token.clone(),
+			bunq_api_key: self.context.bunq_api_key.clone(),
+		}
+	}
+
+	/// Sets how strictly response bodies are parsed. Defaults to
+	/// [`ParsingMode::Lenient`].
+	pub fn set_parsing_mode(&mut self, parsing_mode: ParsingMode) {
+		self.messenger.set_parsing_mode(parsing_mode);
+	}
+
+	/// Sets whether responses keep the exact body bytes, available through
+	/// [`BunqResponse::raw_body`]. Off by default.
+	pub fn set_keep_raw_body(&mut self, keep_raw_body: bool) {
+		self.messenger.set_keep_raw_body(keep_raw_body);
+	}
+
+	/// Makes requests wait for `rate_limiter` instead of being rejected by
+	/// Bunq with HTTP 429, or disables this with `None`. Off by default.
+	///
+	/// Give clones of one [`RateLimiter`] to all clients that use the same
+	/// API key, so they share its budget.
+	pub fn set_rate_limiter(&mut self, rate_limiter: Option<RateLimiter>) {
+		self.messenger.set_rate_limiter(rate_limiter);
+	}
+
+	/// Sets how often a request that Bunq rejects with HTTP 429 is sent again
+	/// after a backoff. Off by default. See
+	/// [`Messenger::set_rate_limit_retries`].
+	pub fn set_rate_limit_retries(&mut self, retries: u32) {
+		self.messenger.set_rate_limit_retries(retries);
+	}
+
+	/// Sets when requests that failed with a network error or HTTP 5xx are
+	/// sent again, or disables this with `None`. Off by default.
+	///
+	/// Only `GET` requests and `POST` requests with an
+	/// `X-Bunq-Client-Request-Id` header are retried, see [`RetryPolicy`].
+	pub fn set_retry_policy(&mut self, retry_policy: Option<RetryPolicy>) {
+		self.messenger.set_retry_policy(retry_policy);
+	}
+
+	/// Sets whether responses must echo the `X-Bunq-Client-Request-Id` their
+	/// request was sent with. Off by default. See
+	/// [`Messenger::set_verify_request_id`].
+	pub fn set_verify_request_id(&mut self, verify_request_id: bool) {
+		self.messenger.set_verify_request_id(verify_request_id);
+	}
+
+	/// Sets the `User-Agent` header sent with every request. See
+	/// [`Messenger::set_user_agent`].
+	pub fn set_user_agent(&mut self, user_agent: impl Into<String>) -> Result<(), ValidationError> {
+		self.messenger.set_user_agent(user_agent)
+	}
+
+	/// Sets the location sent as `X-Bunq-Geolocation` with every request, or
+	/// stops sending the header with `None`. See
+	/// [`Messenger::set_geolocation`].
+	pub fn set_geolocation(&mut self, geolocation: Option<Geolocation>) {
+		self.messenger.set_geolocation(geolocation);
+	}
+
+	/// Sets the language of translated texts from Bunq, such as error
+	/// descriptions, as a locale like `nl_NL`. See
+	/// [`Messenger::set_language`].
+	pub fn set_language(&mut self, language: Option<String>) -> Result<(), ValidationError> {
+		self.messenger.set_language(language)
+	}
+
+	/// Sets the region Bunq formats amounts and dates for, as a locale like
+	/// `nl_NL`. See [`Messenger::set_region`].
+	pub fn set_region(&mut self, region: Option<String>) -> Result<(), ValidationError> {
+		self.messenger.set_region(region)
+	}
+
+	/// Replaces the HTTP client used to send requests. See
+	/// [`ClientBuilder::with_http_client`](crate::client_builder::ClientBuilder::with_http_client).
+	pub fn set_http_client(&mut self, http_client: reqwest::Client) {
+		self.messenger.set_http_client(http_client);
 	}
 
 	// =========================================================================
 	// Endpoints
 	// =========================================================================
 
+	/// Returns the IDs of the installations made with the current key pair.
+	///
+	/// Bunq API: `GET /installation`
+	pub async fn get_installations(&self) -> BunqResponse<Multiple<InstallationIdWrapper>> {
+		self.messenger
+			.send(Method::GET, "installation", None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the ID of a single installation.
+	///
+	/// Bunq API: `GET /installation/{installationId}`
+	pub async fn get_installation(
+		&self,
+		installation_id: u32,
+	) -> BunqResponse<Single<InstallationIdWrapper>> {
+		let endpoint = format!("installation/{installation_id}");
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Re-fetches Bunq's public key for an installation.
+	///
+	/// Use this to recover the key when the stored copy in
+	/// [`SessionContext::bunq_public_key`] is lost, instead of creating a new
+	/// installation. The response signature is **not** verified, because
+	/// verifying it requires the very key being fetched.
+	///
+	/// Bunq API: `GET /installation/{installationId}/server-public-key`
+	pub async fn get_server_public_key(
+		&self,
+		installation_id: u32,
+	) -> BunqResponse<Single<ServerPublicKeyWrapper>> {
+		let endpoint = format!("installation/{installation_id}/server-public-key");
+		self.messenger
+			.send_unverified(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
 	/// Returns the user account associated with the current session.
 	///
 	/// Bunq API: `GET /user`
-	pub async fn get_user(&self) -> ApiResponse<Single<User>> {
+	pub async fn get_user(&self) -> BunqResponse<Single<User>> {
 		self.messenger
 			.send(Method::GET, "user", None)
 			.await
 			.expect("Failed to send request to Bunq")
 	}
 
+	/// Changes the main and/or postal address of the session's user.
+	///
+	/// Bunq API: `PUT /user-person/{userId}`
+	pub async fn update_user_person_address(
+		&self,
+		changes: AlterUserAddress,
+	) -> BunqResponse<Single<AlterUserResponseWrapper>> {
+		let endpoint = format!("user-person/{}", self.context.owner_id);
+		let body = serde_json::to_string(&changes)
+			.expect("Failed to serialize update_user_person_address body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the session's user as a company, for business accounts.
+	///
+	/// Bunq API: `GET /user-company/{userId}`
+	pub async fn get_user_company(&self) -> BunqResponse<Single<UserCompanyWrapper>> {
+		let endpoint = format!("user-company/{}", self.context.owner_id);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Changes the main and/or postal address of the session's company user.
+	///
+	/// Bunq API: `PUT /user-company/{userId}`
+	pub async fn update_user_company_address(
+		&self,
+		changes: AlterUserAddress,
+	) -> BunqResponse<Single<AlterUserResponseWrapper>> {
+		let endpoint = format!("user-company/{}", self.context.owner_id);
+		let body = serde_json::to_string(&changes)
+			.expect("Failed to serialize update_user_company_address body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
 	/// Returns all monetary accounts for the session's user.
 	///
 	/// Bunq API: `GET /user/{userId}/monetary-account-bank`
-	pub async fn get_monetary_accounts(&self) -> ApiResponse<Multiple<MonetaryAccountBankWrapper>> {
+	pub async fn get_monetary_accounts(
+		&self,
+	) -> BunqResponse<Multiple<MonetaryAccountBankWrapper>> {
 		let endpoint = format!("user/{}/monetary-account-bank", self.context.owner_id);
 		self.messenger
 			.send(Method::GET, &endpoint, None)
@@ -117,8 +627,8 @@ impl Client {
 	/// Bunq API: `GET /user/{userId}/monetary-account-bank/{accountId}`
 	pub async fn get_monetary_account(
 		&self,
-		bank_account_id: u32,
-	) -> ApiResponse<Single<MonetaryAccountBankWrapper>> {
+		bank_account_id: MonetaryAccountId,
+	) -> BunqResponse<Single<MonetaryAccountBankWrapper>> {
 		let endpoint = format!(
 			"user/{}/monetary-account-bank/{}",
 			self.context.owner_id, bank_account_id
@@ -134,9 +644,9 @@ impl Client {
 	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/bunqme-tab/{tabId}`
 	pub async fn get_payment_request(
 		&self,
-		monetary_account_id: u32,
-		payment_request_id: u32,
-	) -> ApiResponse<Single<BunqMeTabWrapper>> {
+		monetary_account_id: MonetaryAccountId,
+		payment_request_id: BunqMeTabId,
+	) -> BunqResponse<Single<BunqMeTabWrapper>> {
 		let endpoint = format!(
 			"user/{}/monetary-account/{monetary_account_id}/bunqme-tab/{payment_request_id}",
 			self.context.owner_id
@@ -149,62 +659,1828 @@ impl Client {
 
 	/// Creates a new bunq.me payment request (BunqMeTab).
 	///
-	/// `amount` is always interpreted as EUR. The returned response contains
-	/// the ID of the newly created tab.
+	/// The input is checked before anything is sent. The account is then
+	/// fetched to check that `amount` is in its currency; this works for bank,
+	/// savings and joint accounts alike. If a check fails, no payment request
+	/// is created and the [`ValidationError`] is returned; if the account
+	/// cannot be fetched, its error response is returned. Use
+	/// [`create_payment_request_with_options`](Self::create_payment_request_with_options)
+	/// for more options. The returned handle can be used to fetch, close or
+	/// reopen the new tab.
 	///
-	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/bunqme-tab`
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}`,
+	/// `POST /user/{userId}/monetary-account/{accountId}/bunqme-tab`
 	pub async fn create_payment_request(
 		&self,
-		monetary_account_id: u32,
-		amount: Decimal,
+		monetary_account_id: MonetaryAccountId,
+		amount: Amount,
 		description: String,
 		redirect_url: String,
-	) -> ApiResponse<Single<CreateBunqMeTabResponseWrapper>> {
+	) -> Result<BunqResponse<BunqMeTabHandle>, ValidationError> {
+		let tab = BunqMeTabBuilder::new(amount, description)
+			.redirect_url(redirect_url)
+			.build()?;
+
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}",
+			self.context.owner_id
+		);
+		let account: BunqResponse<Single<AnyMonetaryAccount>> = self
+			.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq");
+		let Single(account) = match account.value_or_error() {
+			Ok(account) => account,
+			Err(error_response) => return Ok(error_response),
+		};
+		if account.currency != tab.amount_inquired.currency {
+			return Err(CurrencyMismatch {
+				account: account.currency,
+				requested: tab.amount_inquired.currency,
+			}
+			.into());
+		}
+
+		Ok(self
+			.create_payment_request_with_options(monetary_account_id, tab)
+			.await)
+	}
+
+	/// Creates a new bunq.me payment request (BunqMeTab) from a body built
+	/// with [`BunqMeTabBuilder`].
+	///
+	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/bunqme-tab`
+	pub async fn create_payment_request_with_options(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		tab: CreateBunqMeTab,
+	) -> BunqResponse<BunqMeTabHandle> {
 		let endpoint = format!(
 			"user/{}/monetary-account/{monetary_account_id}/bunqme-tab",
 			self.context.owner_id
 		);
 
 		let body = CreateBunqMeTabWrapper {
-			bunqme_tab_entry: CreateBunqMeTab {
-				amount_inquired: Amount {
-					value: amount,
-					currency: "EUR".to_string(),
-				},
-				description,
-				redirect_url,
-			},
+			bunqme_tab_entry: tab,
 		};
 
 		let body =
 			serde_json::to_string(&body).expect("Failed to serialize create_payment_request body");
 
-		self.messenger
+		let response: BunqResponse<Single<CreateBunqMeTabResponseWrapper>> = self
+			.messenger
 			.send(Method::POST, &endpoint, Some(body))
 			.await
-			.expect("Failed to send request to Bunq")
+			.expect("Failed to send request to Bunq");
+		response.map(|created| BunqMeTabHandle {
+			monetary_account_id,
+			id: BunqMeTabId(created.id.id),
+		})
+	}
+
+	/// Polls a bunq.me payment request every `poll_interval` until it is paid,
+	/// has expired or was cancelled, and returns how it ended.
+	///
+	/// A request counts as paid as soon as Bunq lists a payment for it, even
+	/// while the tab itself stays open for more payers. `poll_interval` is
+	/// raised to at least [`MIN_POLL_INTERVAL`], and polls that Bunq answers
+	/// with HTTP 429 are simply repeated after the next interval. Returns
+	/// [`WaitForPaymentError::TimedOut`] if the request is still open after
+	/// `timeout`.
+	///
+	/// ```no_run
+	/// # async fn example(client: bunqers::client::Client, handle: bunqers::types::BunqMeTabHandle) {
+	/// use std::time::Duration;
+	/// use bunqers::types::PaymentRequestOutcome;
+	///
+	/// let outcome = client
+	///     .wait_for_payment(
+	///         handle.monetary_account_id,
+	///         handle.id,
+	///         Duration::from_secs(5),
+	///         Duration::from_secs(15 * 60),
+	///     )
+	///     .await;
+	/// if let Ok(PaymentRequestOutcome::Paid(payments)) = outcome {
+	///     println!("Received {} payment(s)", payments.len());
+	/// }
+	/// # }
+	/// ```
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/bunqme-tab/{tabId}`
+	#[cfg(feature = "polling")]
+	pub async fn wait_for_payment(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		payment_request_id: BunqMeTabId,
+		poll_interval: Duration,
+		timeout: Duration,
+	) -> Result<PaymentRequestOutcome, WaitForPaymentError> {
+		let poll_interval = poll_interval.max(MIN_POLL_INTERVAL);
+		let deadline = tokio::time::Instant::now() + timeout;
+		loop {
+			let response = self
+				.get_payment_request(monetary_account_id, payment_request_id)
+				.await;
+			if !response.is_rate_limited() {
+				let tab = response
+					.into_result()
+					.map_err(WaitForPaymentError::Api)?
+					.0
+					.into_inner();
+				if !tab.result_inquiries.is_empty() || tab.status == BunqMeTabStatus::Paid {
+					let payments = tab
+						.result_inquiries
+						.into_iter()
+						.map(|inquiry| inquiry.payment.into_inner())
+						.collect();
+					return Ok(PaymentRequestOutcome::Paid(payments));
+				}
+				match tab.status {
+					BunqMeTabStatus::Expired => return Ok(PaymentRequestOutcome::Expired),
+					BunqMeTabStatus::Cancelled => return Ok(PaymentRequestOutcome::Cancelled),
+					_ => {}
+				}
+			}
+
+			let now = tokio::time::Instant::now();
+			if now >= deadline {
+				return Err(WaitForPaymentError::TimedOut);
+			}
+			tokio::time::sleep(poll_interval.min(deadline - now)).await;
+		}
 	}
 
-	/// Cancels an open bunq.me payment request (BunqMeTab).
+	/// Updates the status and/or the note of a bunq.me payment request
+	/// (BunqMeTab). `note` replaces the description shown to the payer;
+	/// arguments that are `None` are left unchanged.
 	///
 	/// Bunq API: `PUT /user/{userId}/monetary-account/{accountId}/bunqme-tab/{tabId}`
-	pub async fn close_payment_request(
+	pub async fn update_payment_request(
 		&self,
-		monetary_account_id: u32,
-		payment_request_id: u32,
-	) -> ApiResponse<Single<CreateBunqMeTabResponseWrapper>> {
+		monetary_account_id: MonetaryAccountId,
+		payment_request_id: BunqMeTabId,
+		status: Option<BunqMeTabStatus>,
+		note: Option<String>,
+	) -> BunqResponse<Single<AlterBunqMeTabResponseWrapper>> {
 		let endpoint = format!(
 			"user/{}/monetary-account/{monetary_account_id}/bunqme-tab/{payment_request_id}",
 			self.context.owner_id
 		);
 		let body = AlterBunqMeTabRequest {
-			status: Some(BunqMeTabStatus::Cancelled),
+			status,
+			bunqme_tab_entry: note.map(|note| AlterBunqMeTab {
+				description: Some(note),
+				..Default::default()
+			}),
 		};
 		let body =
-			serde_json::to_string(&body).expect("Failed to serialize close_payment_request body");
+			serde_json::to_string(&body).expect("Failed to serialize update_payment_request body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Cancels an open bunq.me payment request (BunqMeTab). See
+	/// [`update_payment_request`](Self::update_payment_request).
+	///
+	/// Bunq API: `PUT /user/{userId}/monetary-account/{accountId}/bunqme-tab/{tabId}`
+	pub async fn close_payment_request(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		payment_request_id: BunqMeTabId,
+	) -> BunqResponse<Single<AlterBunqMeTabResponseWrapper>> {
+		self.update_payment_request(
+			monetary_account_id,
+			payment_request_id,
+			Some(BunqMeTabStatus::Cancelled),
+			None,
+		)
+		.await
+	}
+
+	/// Reopens a cancelled bunq.me payment request (BunqMeTab) so it can be
+	/// paid again. See [`update_payment_request`](Self::update_payment_request).
+	///
+	/// Bunq returns an error if the request can no longer be reopened, e.g.
+	/// because it has expired.
+	///
+	/// Bunq API: `PUT /user/{userId}/monetary-account/{accountId}/bunqme-tab/{tabId}`
+	pub async fn reopen_payment_request(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		payment_request_id: BunqMeTabId,
+	) -> BunqResponse<Single<AlterBunqMeTabResponseWrapper>> {
+		self.update_payment_request(
+			monetary_account_id,
+			payment_request_id,
+			Some(BunqMeTabStatus::WaitingForPayment),
+			None,
+		)
+		.await
+	}
+
+	/// Returns all cash registers of a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/cash-register`
+	pub async fn get_cash_registers(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<CashRegisterWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a specific cash register.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}`
+	pub async fn get_cash_register(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+	) -> BunqResponse<Single<CashRegisterWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Creates a new single-use tab under a cash register.
+	///
+	/// The returned response contains the UUID of the newly created tab.
+	///
+	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab-usage-single`
+	pub async fn create_tab_usage_single(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab: CreateTabUsageSingle,
+	) -> BunqResponse<Single<CreateTabResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab-usage-single",
+			self.context.owner_id
+		);
+		let body =
+			serde_json::to_string(&tab).expect("Failed to serialize create_tab_usage_single body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Updates the amount, status, description or visibility of a single-use
+	/// tab.
+	///
+	/// Bunq API: `PUT /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab-usage-single/{tabUuid}`
+	pub async fn update_tab_usage_single(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab_uuid: &str,
+		changes: AlterTabUsageSingle,
+	) -> BunqResponse<Single<CreateTabResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab-usage-single/{tab_uuid}",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&changes)
+			.expect("Failed to serialize update_tab_usage_single body");
 		self.messenger
 			.send(Method::PUT, &endpoint, Some(body))
 			.await
 			.expect("Failed to send request to Bunq")
 	}
+
+	/// Returns a single-use tab by UUID, including its visibility settings.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab-usage-single/{tabUuid}`
+	pub async fn get_tab_usage_single(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab_uuid: &str,
+	) -> BunqResponse<Single<TabUsageSingleWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab-usage-single/{tab_uuid}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns all single-use tabs of a cash register.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab-usage-single`
+	pub async fn get_tab_usage_singles(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+	) -> BunqResponse<Multiple<TabUsageSingleWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab-usage-single",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Creates a new reusable tab under a cash register.
+	///
+	/// The returned response contains the UUID of the newly created tab.
+	///
+	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab-usage-multiple`
+	pub async fn create_tab_usage_multiple(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab: CreateTabUsageMultiple,
+	) -> BunqResponse<Single<CreateTabResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab-usage-multiple",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&tab)
+			.expect("Failed to serialize create_tab_usage_multiple body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Updates the amount, status, description, visibility or expiration of a
+	/// reusable tab.
+	///
+	/// Bunq API: `PUT /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab-usage-multiple/{tabUuid}`
+	pub async fn update_tab_usage_multiple(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab_uuid: &str,
+		changes: AlterTabUsageMultiple,
+	) -> BunqResponse<Single<CreateTabResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab-usage-multiple/{tab_uuid}",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&changes)
+			.expect("Failed to serialize update_tab_usage_multiple body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a reusable tab by UUID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab-usage-multiple/{tabUuid}`
+	pub async fn get_tab_usage_multiple(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab_uuid: &str,
+	) -> BunqResponse<Single<TabUsageMultipleWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab-usage-multiple/{tab_uuid}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns all reusable tabs of a cash register.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab-usage-multiple`
+	pub async fn get_tab_usage_multiples(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+	) -> BunqResponse<Multiple<TabUsageMultipleWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab-usage-multiple",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Adds a product line to an open tab.
+	///
+	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab/{tabUuid}/tab-item`
+	pub async fn create_tab_item(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab_uuid: &str,
+		item: CreateTabItemShop,
+	) -> BunqResponse<Single<CreateTabItemShopResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab/{tab_uuid}/tab-item",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&item).expect("Failed to serialize create_tab_item body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns all product lines of a tab.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab/{tabUuid}/tab-item`
+	pub async fn get_tab_items(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab_uuid: &str,
+	) -> BunqResponse<Multiple<TabItemShopWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab/{tab_uuid}/tab-item",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single product line of a tab by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab/{tabUuid}/tab-item/{itemId}`
+	pub async fn get_tab_item(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab_uuid: &str,
+		tab_item_id: u32,
+	) -> BunqResponse<Single<TabItemShopWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab/{tab_uuid}/tab-item/{tab_item_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Updates a product line of an open tab.
+	///
+	/// Bunq API: `PUT /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab/{tabUuid}/tab-item/{itemId}`
+	pub async fn update_tab_item(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab_uuid: &str,
+		tab_item_id: u32,
+		changes: AlterTabItemShop,
+	) -> BunqResponse<Single<CreateTabItemShopResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab/{tab_uuid}/tab-item/{tab_item_id}",
+			self.context.owner_id
+		);
+		let body =
+			serde_json::to_string(&changes).expect("Failed to serialize update_tab_item body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Removes a product line from an open tab.
+	///
+	/// Bunq API: `DELETE /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab/{tabUuid}/tab-item/{itemId}`
+	pub async fn delete_tab_item(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab_uuid: &str,
+		tab_item_id: u32,
+	) -> BunqResponse<Empty> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab/{tab_uuid}/tab-item/{tab_item_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::DELETE, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Downloads the QR code of a tab as PNG image bytes.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/cash-register/{cashRegisterId}/tab/{tabUuid}/qr-code-content`
+	pub async fn get_tab_qr_code(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cash_register_id: u32,
+		tab_uuid: &str,
+	) -> BunqResponse<Vec<u8>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/cash-register/{cash_register_id}/tab/{tab_uuid}/qr-code-content",
+			self.context.owner_id
+		);
+		self.messenger
+			.send_raw(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Downloads the QR code of a draft share invite as PNG image bytes.
+	///
+	/// Bunq API: `GET /user/{userId}/draft-share-invite-bank/{inviteId}/qr-code-content`
+	pub async fn get_draft_share_invite_qr_code(
+		&self,
+		draft_share_invite_id: u32,
+	) -> BunqResponse<Vec<u8>> {
+		let endpoint = format!(
+			"user/{}/draft-share-invite-bank/{draft_share_invite_id}/qr-code-content",
+			self.context.owner_id
+		);
+		self.messenger
+			.send_raw(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Initiates a balance top-up through iDEAL.
+	///
+	/// Fetch the created transaction with
+	/// [`get_ideal_merchant_transaction`](Self::get_ideal_merchant_transaction)
+	/// to obtain the URL the payer has to visit.
+	///
+	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/ideal-merchant-transaction`
+	pub async fn create_ideal_merchant_transaction(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		transaction: CreateIdealMerchantTransaction,
+	) -> BunqResponse<Single<CreateIdealMerchantTransactionResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/ideal-merchant-transaction",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&transaction)
+			.expect("Failed to serialize create_ideal_merchant_transaction body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single iDEAL merchant transaction by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/ideal-merchant-transaction/{transactionId}`
+	pub async fn get_ideal_merchant_transaction(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		ideal_merchant_transaction_id: u32,
+	) -> BunqResponse<Single<IdealMerchantTransactionWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/ideal-merchant-transaction/{ideal_merchant_transaction_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns all iDEAL merchant transactions of a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/ideal-merchant-transaction`
+	pub async fn get_ideal_merchant_transactions(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<IdealMerchantTransactionWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/ideal-merchant-transaction",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Submits the token of a scanned iDEAL QR code, turning it into a payment
+	/// request that can be accepted to complete the iDEAL payment.
+	///
+	/// Bunq API: `POST /user/{userId}/token-qr-request-ideal`
+	pub async fn create_token_qr_request_ideal(
+		&self,
+		token: String,
+	) -> BunqResponse<Single<TokenQrRequestIdealWrapper>> {
+		let endpoint = format!("user/{}/token-qr-request-ideal", self.context.owner_id);
+		let body = serde_json::to_string(&CreateTokenQrRequestIdeal { token })
+			.expect("Failed to serialize create_token_qr_request_ideal body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Requests a TransferWise quote for a cross-currency payment.
+	///
+	/// Bunq API: `POST /user/{userId}/transferwise-quote`
+	pub async fn create_transferwise_quote(
+		&self,
+		quote: CreateTransferwiseQuote,
+	) -> BunqResponse<Single<CreateTransferwiseResponseWrapper>> {
+		let endpoint = format!("user/{}/transferwise-quote", self.context.owner_id);
+		let body = serde_json::to_string(&quote)
+			.expect("Failed to serialize create_transferwise_quote body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single TransferWise quote by ID, including its exchange rate
+	/// and fee.
+	///
+	/// Bunq API: `GET /user/{userId}/transferwise-quote/{quoteId}`
+	pub async fn get_transferwise_quote(
+		&self,
+		transferwise_quote_id: u32,
+	) -> BunqResponse<Single<TransferwiseQuoteWrapper>> {
+		let endpoint = format!(
+			"user/{}/transferwise-quote/{transferwise_quote_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Executes a cross-currency payment using a previously obtained quote.
+	///
+	/// Bunq API: `POST /user/{userId}/transferwise-quote/{quoteId}/transferwise-transfer`
+	pub async fn create_transferwise_transfer(
+		&self,
+		transferwise_quote_id: u32,
+		transfer: CreateTransferwiseTransfer,
+	) -> BunqResponse<Single<CreateTransferwiseResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/transferwise-quote/{transferwise_quote_id}/transferwise-transfer",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&transfer)
+			.expect("Failed to serialize create_transferwise_transfer body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single TransferWise transfer by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/transferwise-quote/{quoteId}/transferwise-transfer/{transferId}`
+	pub async fn get_transferwise_transfer(
+		&self,
+		transferwise_quote_id: u32,
+		transferwise_transfer_id: u32,
+	) -> BunqResponse<Single<TransferwiseTransferWrapper>> {
+		let endpoint = format!(
+			"user/{}/transferwise-quote/{transferwise_quote_id}/transferwise-transfer/{transferwise_transfer_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns all TransferWise transfers made with a quote.
+	///
+	/// Bunq API: `GET /user/{userId}/transferwise-quote/{quoteId}/transferwise-transfer`
+	pub async fn get_transferwise_transfers(
+		&self,
+		transferwise_quote_id: u32,
+	) -> BunqResponse<Multiple<TransferwiseTransferWrapper>> {
+		let endpoint = format!(
+			"user/{}/transferwise-quote/{transferwise_quote_id}/transferwise-transfer",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Creates a rule that automatically splits incoming payments.
+	///
+	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/payment-auto-allocate`
+	pub async fn create_payment_auto_allocate(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		rule: CreatePaymentAutoAllocate,
+	) -> BunqResponse<Single<CreatePaymentAutoAllocateResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/payment-auto-allocate",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&rule)
+			.expect("Failed to serialize create_payment_auto_allocate body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns all payment auto allocation rules of a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/payment-auto-allocate`
+	pub async fn get_payment_auto_allocates(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<PaymentAutoAllocateWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/payment-auto-allocate",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single payment auto allocation rule by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/payment-auto-allocate/{ruleId}`
+	pub async fn get_payment_auto_allocate(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		payment_auto_allocate_id: u32,
+	) -> BunqResponse<Single<PaymentAutoAllocateWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/payment-auto-allocate/{payment_auto_allocate_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Replaces the splits of a payment auto allocation rule.
+	///
+	/// Bunq API: `PUT /user/{userId}/monetary-account/{accountId}/payment-auto-allocate/{ruleId}`
+	pub async fn update_payment_auto_allocate(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		payment_auto_allocate_id: u32,
+		changes: AlterPaymentAutoAllocate,
+	) -> BunqResponse<Single<CreatePaymentAutoAllocateResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/payment-auto-allocate/{payment_auto_allocate_id}",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&changes)
+			.expect("Failed to serialize update_payment_auto_allocate body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Deletes a payment auto allocation rule.
+	///
+	/// Bunq API: `DELETE /user/{userId}/monetary-account/{accountId}/payment-auto-allocate/{ruleId}`
+	pub async fn delete_payment_auto_allocate(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		payment_auto_allocate_id: u32,
+	) -> BunqResponse<Empty> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/payment-auto-allocate/{payment_auto_allocate_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::DELETE, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Enables auto-save ("save the change") on a monetary account.
+	///
+	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/auto-save`
+	pub async fn create_auto_save(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		rule: CreateAutoSave,
+	) -> BunqResponse<Single<CreateAutoSaveResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/auto-save",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&rule).expect("Failed to serialize create_auto_save body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the auto-save rules of a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/auto-save`
+	pub async fn get_auto_saves(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<AutoSaveWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/auto-save",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Updates an auto-save rule, e.g. to deactivate it.
+	///
+	/// Bunq API: `PUT /user/{userId}/monetary-account/{accountId}/auto-save/{ruleId}`
+	pub async fn update_auto_save(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		auto_save_id: u32,
+		changes: AlterAutoSave,
+	) -> BunqResponse<Single<CreateAutoSaveResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/auto-save/{auto_save_id}",
+			self.context.owner_id
+		);
+		let body =
+			serde_json::to_string(&changes).expect("Failed to serialize update_auto_save body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a payment redirected by the bank switch service, including the
+	/// current status of the switch.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/switch-service-payment/{paymentId}`
+	pub async fn get_switch_service_payment(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		switch_service_payment_id: u32,
+	) -> BunqResponse<Single<BankSwitchServicePaymentWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/switch-service-payment/{switch_service_payment_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the IP addresses permitted to use an API credential.
+	///
+	/// Bunq API: `GET /user/{userId}/credential-password-ip/{credentialId}/ip`
+	pub async fn get_permitted_ips(
+		&self,
+		credential_password_ip_id: u32,
+	) -> BunqResponse<Multiple<PermittedIpWrapper>> {
+		let endpoint = format!(
+			"user/{}/credential-password-ip/{credential_password_ip_id}/ip",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single permitted IP address by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/credential-password-ip/{credentialId}/ip/{ipId}`
+	pub async fn get_permitted_ip(
+		&self,
+		credential_password_ip_id: u32,
+		permitted_ip_id: u32,
+	) -> BunqResponse<Single<PermittedIpWrapper>> {
+		let endpoint = format!(
+			"user/{}/credential-password-ip/{credential_password_ip_id}/ip/{permitted_ip_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Permits an additional IP address (or `*` for any address) to use an API
+	/// credential, without re-registering the device.
+	///
+	/// Bunq API: `POST /user/{userId}/credential-password-ip/{credentialId}/ip`
+	pub async fn create_permitted_ip(
+		&self,
+		credential_password_ip_id: u32,
+		permitted_ip: CreatePermittedIp,
+	) -> BunqResponse<Single<CreatePermittedIpResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/credential-password-ip/{credential_password_ip_id}/ip",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&permitted_ip)
+			.expect("Failed to serialize create_permitted_ip body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Activates or deactivates a permitted IP address.
+	///
+	/// Bunq API: `PUT /user/{userId}/credential-password-ip/{credentialId}/ip/{ipId}`
+	pub async fn update_permitted_ip(
+		&self,
+		credential_password_ip_id: u32,
+		permitted_ip_id: u32,
+		status: PermittedIpStatus,
+	) -> BunqResponse<Single<CreatePermittedIpResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/credential-password-ip/{credential_password_ip_id}/ip/{permitted_ip_id}",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&AlterPermittedIp { status })
+			.expect("Failed to serialize update_permitted_ip body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the API credentials of the session's user.
+	///
+	/// Bunq API: `GET /user/{userId}/credential-password-ip`
+	pub async fn get_credential_password_ips(
+		&self,
+	) -> BunqResponse<Multiple<CredentialPasswordIpWrapper>> {
+		let endpoint = format!("user/{}/credential-password-ip", self.context.owner_id);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single API credential by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/credential-password-ip/{credentialId}`
+	pub async fn get_credential_password_ip(
+		&self,
+		credential_password_ip_id: u32,
+	) -> BunqResponse<Single<CredentialPasswordIpWrapper>> {
+		let endpoint = format!(
+			"user/{}/credential-password-ip/{credential_password_ip_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the payments of a monetary account, newest first.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/payment`
+	pub async fn get_payments(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<PaymentWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/payment",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the page of payments that `cursor` points to, e.g.
+	/// `pagination.older` of a previous [`get_payments`](Self::get_payments)
+	/// call.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/payment?{cursor}`
+	pub async fn get_payments_page(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		cursor: &PageCursor,
+	) -> BunqResponse<Multiple<PaymentWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/payment?{}",
+			self.context.owner_id,
+			cursor.to_query_string()
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single payment by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/payment/{paymentId}`
+	pub async fn get_payment(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		payment_id: PaymentId,
+	) -> BunqResponse<Single<PaymentWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/payment/{payment_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Sets the spending category of a payment.
+	///
+	/// Bunq API: `PUT /user/{userId}/monetary-account/{accountId}/payment/{paymentId}/additional-transaction-information-category`
+	pub async fn set_payment_category(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		payment_id: PaymentId,
+		category: String,
+	) -> BunqResponse<Single<AlterPaymentCategoryResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/payment/{payment_id}/additional-transaction-information-category",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&AlterPaymentCategory { category })
+			.expect("Failed to serialize set_payment_category body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the total spending per category between `date_from` and
+	/// `date_to` (inclusive).
+	///
+	/// Bunq API: `GET /user/{userId}/insights`
+	pub async fn get_insights(
+		&self,
+		date_from: NaiveDate,
+		date_to: NaiveDate,
+	) -> BunqResponse<Multiple<InsightWrapper>> {
+		let endpoint = format!(
+			"user/{}/insights?time_start={}&time_end={}",
+			self.context.owner_id,
+			date_from.format("%Y-%m-%d"),
+			date_to.format("%Y-%m-%d")
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the referral rewards the session's user has given to others.
+	///
+	/// Bunq API: `GET /user/{userId}/reward-sender`
+	pub async fn get_reward_senders(&self) -> BunqResponse<Multiple<RewardSenderWrapper>> {
+		let endpoint = format!("user/{}/reward-sender", self.context.owner_id);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single given referral reward by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/reward-sender/{rewardId}`
+	pub async fn get_reward_sender(
+		&self,
+		reward_id: u32,
+	) -> BunqResponse<Single<RewardSenderWrapper>> {
+		let endpoint = format!("user/{}/reward-sender/{reward_id}", self.context.owner_id);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the referral rewards the session's user has received.
+	///
+	/// Bunq API: `GET /user/{userId}/reward-recipient`
+	pub async fn get_reward_recipients(&self) -> BunqResponse<Multiple<RewardRecipientWrapper>> {
+		let endpoint = format!("user/{}/reward-recipient", self.context.owner_id);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single received referral reward by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/reward-recipient/{rewardId}`
+	pub async fn get_reward_recipient(
+		&self,
+		reward_id: u32,
+	) -> BunqResponse<Single<RewardRecipientWrapper>> {
+		let endpoint = format!(
+			"user/{}/reward-recipient/{reward_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Requests a quote for converting money to another currency.
+	///
+	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/currency-conversion-quote`
+	pub async fn create_currency_conversion_quote(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		quote: CreateCurrencyConversionQuote,
+	) -> BunqResponse<Single<CreateCurrencyConversionQuoteResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/currency-conversion-quote",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&quote)
+			.expect("Failed to serialize create_currency_conversion_quote body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single currency conversion quote by ID, including its rate.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/currency-conversion-quote/{quoteId}`
+	pub async fn get_currency_conversion_quote(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		currency_conversion_quote_id: u32,
+	) -> BunqResponse<Single<CurrencyConversionQuoteWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/currency-conversion-quote/{currency_conversion_quote_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Accepts a currency conversion quote, executing the conversion.
+	///
+	/// Bunq API: `PUT /user/{userId}/monetary-account/{accountId}/currency-conversion-quote/{quoteId}`
+	pub async fn accept_currency_conversion_quote(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		currency_conversion_quote_id: u32,
+	) -> BunqResponse<Single<CreateCurrencyConversionQuoteResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/currency-conversion-quote/{currency_conversion_quote_id}",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&AlterCurrencyConversionQuote {
+			status: CurrencyConversionQuoteStatus::Accepted,
+		})
+		.expect("Failed to serialize accept_currency_conversion_quote body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the executed currency conversions of a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/currency-conversion`
+	pub async fn get_currency_conversions(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<CurrencyConversionWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/currency-conversion",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single executed currency conversion by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/currency-conversion/{conversionId}`
+	pub async fn get_currency_conversion(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		currency_conversion_id: u32,
+	) -> BunqResponse<Single<CurrencyConversionWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/currency-conversion/{currency_conversion_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the certificate chains pinned for callbacks.
+	///
+	/// Bunq API: `GET /user/{userId}/certificate-pinned`
+	pub async fn get_pinned_certificates(
+		&self,
+	) -> BunqResponse<Multiple<CertificatePinnedWrapper>> {
+		let endpoint = format!("user/{}/certificate-pinned", self.context.owner_id);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single pinned certificate chain by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/certificate-pinned/{certificateId}`
+	pub async fn get_pinned_certificate(
+		&self,
+		certificate_pinned_id: u32,
+	) -> BunqResponse<Single<CertificatePinnedWrapper>> {
+		let endpoint = format!(
+			"user/{}/certificate-pinned/{certificate_pinned_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Pins a certificate chain for callbacks.
+	///
+	/// `certificate_chain` holds PEM-encoded certificates, starting with the
+	/// callback server's own certificate.
+	///
+	/// Bunq API: `POST /user/{userId}/certificate-pinned`
+	pub async fn pin_certificate(
+		&self,
+		certificate_chain: Vec<String>,
+	) -> BunqResponse<Single<CreateCertificatePinnedResponseWrapper>> {
+		let endpoint = format!("user/{}/certificate-pinned", self.context.owner_id);
+		let body = CreateCertificatePinned {
+			certificate_chain: certificate_chain
+				.into_iter()
+				.map(|certificate| Certificate { certificate })
+				.collect(),
+		};
+		let body = serde_json::to_string(&body).expect("Failed to serialize pin_certificate body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Removes a pinned certificate chain, e.g. after rotating to a new one.
+	///
+	/// Bunq API: `DELETE /user/{userId}/certificate-pinned/{certificateId}`
+	pub async fn delete_pinned_certificate(
+		&self,
+		certificate_pinned_id: u32,
+	) -> BunqResponse<Empty> {
+		let endpoint = format!(
+			"user/{}/certificate-pinned/{certificate_pinned_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::DELETE, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the schedules of a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/schedule`
+	pub async fn get_schedules(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<ScheduleWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/schedule",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single schedule by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/schedule/{scheduleId}`
+	pub async fn get_schedule(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		schedule_id: u32,
+	) -> BunqResponse<Single<ScheduleWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/schedule/{schedule_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a scheduled payment (e.g. a standing order) by ID, including its
+	/// full recurrence.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/schedule-payment/{schedulePaymentId}`
+	pub async fn get_schedule_payment(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		schedule_payment_id: u32,
+	) -> BunqResponse<Single<SchedulePaymentWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/schedule-payment/{schedule_payment_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Replaces the recurrence of a scheduled payment.
+	///
+	/// Bunq API: `PUT /user/{userId}/monetary-account/{accountId}/schedule-payment/{schedulePaymentId}`
+	pub async fn update_schedule_payment(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		schedule_payment_id: u32,
+		schedule: Schedule,
+	) -> BunqResponse<Single<AlterSchedulePaymentResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/schedule-payment/{schedule_payment_id}",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&AlterSchedulePayment { schedule })
+			.expect("Failed to serialize update_schedule_payment body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the payment requests sent from a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/request-inquiry`
+	pub async fn get_request_inquiries(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<RequestInquiryWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/request-inquiry",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single sent payment request by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/request-inquiry/{requestInquiryId}`
+	pub async fn get_request_inquiry(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		request_inquiry_id: u32,
+	) -> BunqResponse<Single<RequestInquiryWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/request-inquiry/{request_inquiry_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the payment requests received on a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/request-response`
+	pub async fn get_request_responses(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<RequestResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/request-response",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single received payment request by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/request-response/{requestResponseId}`
+	pub async fn get_request_response(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		request_response_id: u32,
+	) -> BunqResponse<Single<RequestResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/request-response/{request_response_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the callback URL subscriptions of the session's user.
+	///
+	/// Bunq API: `GET /user/{userId}/notification-filter-url`
+	pub async fn get_notification_filters_url(
+		&self,
+	) -> BunqResponse<Multiple<NotificationFilterUrlWrapper>> {
+		let endpoint = format!("user/{}/notification-filter-url", self.context.owner_id);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Replaces the callback URL subscriptions of the session's user.
+	///
+	/// Subscriptions that are not in `notification_filters` are removed, so
+	/// pass an empty list to remove all of them.
+	///
+	/// Bunq API: `POST /user/{userId}/notification-filter-url`
+	pub async fn set_notification_filters_url(
+		&self,
+		notification_filters: Vec<NotificationFilterUrl>,
+	) -> BunqResponse<Empty> {
+		let endpoint = format!("user/{}/notification-filter-url", self.context.owner_id);
+		let body = serde_json::to_string(&SetNotificationFilters {
+			notification_filters,
+		})
+		.expect("Failed to serialize set_notification_filters_url body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the callback URL subscriptions of a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/notification-filter-url`
+	pub async fn get_monetary_account_notification_filters_url(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<NotificationFilterUrlWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/notification-filter-url",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Replaces the callback URL subscriptions of a monetary account.
+	///
+	/// Subscriptions that are not in `notification_filters` are removed.
+	///
+	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/notification-filter-url`
+	pub async fn set_monetary_account_notification_filters_url(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		notification_filters: Vec<NotificationFilterUrl>,
+	) -> BunqResponse<Empty> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/notification-filter-url",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&SetNotificationFilters {
+			notification_filters,
+		})
+		.expect("Failed to serialize set_monetary_account_notification_filters_url body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the push notification subscriptions of the session's user.
+	///
+	/// Bunq API: `GET /user/{userId}/notification-filter-push`
+	pub async fn get_notification_filters_push(
+		&self,
+	) -> BunqResponse<Multiple<NotificationFilterPushWrapper>> {
+		let endpoint = format!("user/{}/notification-filter-push", self.context.owner_id);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Replaces the push notification subscriptions of the session's user.
+	///
+	/// Subscriptions that are not in `notification_filters` are removed.
+	///
+	/// Bunq API: `POST /user/{userId}/notification-filter-push`
+	pub async fn set_notification_filters_push(
+		&self,
+		notification_filters: Vec<NotificationFilterPush>,
+	) -> BunqResponse<Empty> {
+		let endpoint = format!("user/{}/notification-filter-push", self.context.owner_id);
+		let body = serde_json::to_string(&SetNotificationFilters {
+			notification_filters,
+		})
+		.expect("Failed to serialize set_notification_filters_push body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the card transactions of a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/mastercard-action`
+	pub async fn get_mastercard_actions(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<MasterCardActionWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/mastercard-action",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single card transaction by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/mastercard-action/{mastercardActionId}`
+	pub async fn get_mastercard_action(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		mastercard_action_id: u32,
+	) -> BunqResponse<Single<MasterCardActionWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/mastercard-action/{mastercard_action_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the cards of the session's user.
+	///
+	/// Bunq API: `GET /user/{userId}/card`
+	pub async fn get_cards(&self) -> BunqResponse<Multiple<CardWrapper>> {
+		let endpoint = format!("user/{}/card", self.context.owner_id);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single card by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/card/{cardId}`
+	pub async fn get_card(&self, card_id: u32) -> BunqResponse<Single<CardWrapper>> {
+		let endpoint = format!("user/{}/card/{card_id}", self.context.owner_id);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Changes the status, limits, country permissions or PIN assignments of
+	/// a card and returns the updated card.
+	///
+	/// Bunq API: `PUT /user/{userId}/card/{cardId}`
+	pub async fn update_card(
+		&self,
+		card_id: u32,
+		update: &UpdateCard,
+	) -> BunqResponse<Single<CardWrapper>> {
+		let endpoint = format!("user/{}/card/{card_id}", self.context.owner_id);
+		let body = serde_json::to_string(update).expect("Failed to serialize update_card body");
+		self.messenger
+			.send(Method::PUT, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Starts exporting a statement of a monetary account. Bunq creates the
+	/// file in the background; download it with
+	/// [`get_customer_statement_content`](Self::get_customer_statement_content).
+	///
+	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/customer-statement`
+	pub async fn create_customer_statement(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		export: CreateCustomerStatementExport,
+	) -> BunqResponse<Single<CreateCustomerStatementExportResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/customer-statement",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&export)
+			.expect("Failed to serialize create_customer_statement body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the statement exports of a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/customer-statement`
+	pub async fn get_customer_statements(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<CustomerStatementExportWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/customer-statement",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single statement export by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/customer-statement/{exportId}`
+	pub async fn get_customer_statement(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		customer_statement_id: u32,
+	) -> BunqResponse<Single<CustomerStatementExportWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/customer-statement/{customer_statement_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Downloads the file of a statement export in its
+	/// [`StatementFormat`].
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/customer-statement/{exportId}/content`
+	pub async fn get_customer_statement_content(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		customer_statement_id: u32,
+	) -> BunqResponse<Vec<u8>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/customer-statement/{customer_statement_id}/content",
+			self.context.owner_id
+		);
+		self.messenger
+			.send_raw(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Prepares one or more payments that the user has to accept in the app
+	/// before they are executed.
+	///
+	/// Bunq API: `POST /user/{userId}/monetary-account/{accountId}/draft-payment`
+	pub async fn create_draft_payment(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		draft_payment: CreateDraftPayment,
+	) -> BunqResponse<Single<CreateDraftPaymentResponseWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/draft-payment",
+			self.context.owner_id
+		);
+		let body = serde_json::to_string(&draft_payment)
+			.expect("Failed to serialize create_draft_payment body");
+		self.messenger
+			.send(Method::POST, &endpoint, Some(body))
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns the draft payments of a monetary account.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/draft-payment`
+	pub async fn get_draft_payments(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+	) -> BunqResponse<Multiple<DraftPaymentWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/draft-payment",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+
+	/// Returns a single draft payment by ID.
+	///
+	/// Bunq API: `GET /user/{userId}/monetary-account/{accountId}/draft-payment/{draftPaymentId}`
+	pub async fn get_draft_payment(
+		&self,
+		monetary_account_id: MonetaryAccountId,
+		draft_payment_id: u32,
+	) -> BunqResponse<Single<DraftPaymentWrapper>> {
+		let endpoint = format!(
+			"user/{}/monetary-account/{monetary_account_id}/draft-payment/{draft_payment_id}",
+			self.context.owner_id
+		);
+		self.messenger
+			.send(Method::GET, &endpoint, None)
+			.await
+			.expect("Failed to send request to Bunq")
+	}
+}
+
+impl BunqMeTabHandle {
+	/// Fetches the current state of the payment request.
+	pub async fn get(&self, client: &Client) -> BunqResponse<Single<BunqMeTabWrapper>> {
+		client
+			.get_payment_request(self.monetary_account_id, self.id)
+			.await
+	}
+
+	/// Waits until the payment request is settled. See
+	/// [`Client::wait_for_payment`].
+	#[cfg(feature = "polling")]
+	pub async fn wait_for_payment(
+		&self,
+		client: &Client,
+		poll_interval: Duration,
+		timeout: Duration,
+	) -> Result<PaymentRequestOutcome, WaitForPaymentError> {
+		client
+			.wait_for_payment(self.monetary_account_id, self.id, poll_interval, timeout)
+			.await
+	}
+
+	/// Cancels the payment request. See [`Client::close_payment_request`].
+	pub async fn close(
+		&self,
+		client: &Client,
+	) -> BunqResponse<Single<AlterBunqMeTabResponseWrapper>> {
+		client
+			.close_payment_request(self.monetary_account_id, self.id)
+			.await
+	}
+
+	/// Reopens the cancelled payment request. See
+	/// [`Client::reopen_payment_request`].
+	pub async fn reopen(
+		&self,
+		client: &Client,
+	) -> BunqResponse<Single<AlterBunqMeTabResponseWrapper>> {
+		client
+			.reopen_payment_request(self.monetary_account_id, self.id)
+			.await
+	}
 }
@@ -22,21 +22,30 @@
 //! [`ClientBuilder::from_registration`] to start directly at the `Registered`
 //! state, or [`ClientBuilder::from_unchecked_session`] to attempt reusing a
 //! cached session token.
+//!
+//! Bunq throttles the setup endpoints, so by default the builder waits a few
+//! seconds between steps. See [`Pacing`].
 
-use openssl::{
-	error::ErrorStack,
-	pkey::{PKey, Private, Public},
-	rsa::Rsa,
-};
-use reqwest::Method;
+use std::{any::Any, error::Error, fmt, fs, io, path::Path, time::Duration};
+
+use reqwest::{Method, Url};
+use serde::{Deserialize, Serialize};
 
 use crate::{
 	client::{Client, SessionContext},
-	messenger::{ApiErrorResponse, ApiResponse, MessageError, Messenger},
+	crypto::{self, KeyError, PrivateKey, PublicKey},
+	deserialization::{deserialize_public_key, serialize_public_key},
+	messenger::{
+		ApiErrorResponse, BunqErrorKind, BunqResponse, Geolocation, MessageError, Messenger,
+	},
+	session_state::{SessionStage, SessionState},
+	signing_key::{KeySpec, SigningKey},
+	sleep::{Instant, Sleep},
 	types::{
-		CreateDeviceServer, CreateInstallation, CreateSession, DeviceServerSmall, Installation,
-		Session as BunqSession, Single, User,
+		CreateDeviceServer, CreateInstallation, CreateSession, DeviceId, DeviceServerSmall,
+		Installation, Session as BunqSession, Single, User,
 	},
+	validation::{ValidationError, validate_device_description},
 };
 
 impl From<SessionContext> for UncheckedSession {
@@ -57,12 +66,17 @@ impl From<SessionContext> for UncheckedSession {
 /// Use [`ClientBuilder::from_unchecked_session`] to enter this state when
 /// restoring a session from e.g. disk, then call
 /// [`ClientBuilder::check_session`] to validate it.
+#[derive(Serialize, Deserialize)]
 pub struct UncheckedSession {
 	pub session_token: String,
-	pub registered_device_id: u32,
+	pub registered_device_id: DeviceId,
 	pub bunq_api_key: String,
 	pub installation_token: String,
-	pub bunq_public_key: PKey<Public>,
+	#[serde(
+		serialize_with = "serialize_public_key",
+		deserialize_with = "deserialize_public_key"
+	)]
+	pub bunq_public_key: PublicKey,
 }
 
 impl From<UncheckedSession> for Registered {
@@ -81,12 +95,37 @@ impl From<UncheckedSession> for Registered {
 /// Obtained after [`ClientBuilder::register_device`] succeeds, or constructed
 /// directly via [`ClientBuilder::from_registration`] when restoring a
 /// persisted [`crate::InstallationContext`].
-#[derive(Clone, Debug)]
+///
+/// Serialises to JSON with Bunq's public key as a PEM string:
+///
+/// ```
+/// use bunqers::client_builder::Registered;
+/// use openssl::{pkey::PKey, rsa::Rsa};
+///
+/// let rsa = Rsa::generate(2048).unwrap();
+/// let public_key = PKey::public_key_from_pem(&rsa.public_key_to_pem().unwrap()).unwrap();
+/// let registered = Registered {
+///     registered_device_id: 42.into(),
+///     bunq_api_key: "api-key".into(),
+///     installation_token: "installation-token".into(),
+///     bunq_public_key: public_key,
+/// };
+///
+/// let json = serde_json::to_string(&registered).unwrap();
+/// let restored: Registered = serde_json::from_str(&json).unwrap();
+/// assert_eq!(restored.registered_device_id, registered.registered_device_id);
+/// assert!(restored.bunq_public_key.public_eq(&registered.bunq_public_key));
+/// ```
+#[derive(Serialize, Deserialize, Clone)]
 pub struct Registered {
-	pub registered_device_id: u32,
+	pub registered_device_id: DeviceId,
 	pub bunq_api_key: String,
 	pub installation_token: String,
-	pub bunq_public_key: PKey<Public>,
+	#[serde(
+		serialize_with = "serialize_public_key",
+		deserialize_with = "deserialize_public_key"
+	)]
+	pub bunq_public_key: PublicKey,
 }
 
 impl From<Registered> for Installed {
@@ -100,10 +139,149 @@ impl From<Registered> for Installed {
 
 /// Builder state: the `/installation` endpoint has been called and Bunq's
 /// public key is available, but no device has been registered yet.
-#[derive(Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct Installed {
 	pub installation_token: String,
-	pub bunq_public_key: PKey<Public>,
+	#[serde(
+		serialize_with = "serialize_public_key",
+		deserialize_with = "deserialize_public_key"
+	)]
+	pub bunq_public_key: PublicKey,
+}
+
+impl UncheckedSession {
+	/// Returns a value whose `Debug` output includes the secrets.
+	pub fn reveal(&self) -> Revealed<'_, Self> {
+		Revealed(self)
+	}
+
+	fn fmt_debug(&self, f: &mut fmt::Formatter<'_>, reveal: bool) -> fmt::Result {
+		f.debug_struct("UncheckedSession")
+			.field("session_token", &Secret(&self.session_token, reveal))
+			.field("registered_device_id", &self.registered_device_id)
+			.field("bunq_api_key", &Secret(&self.bunq_api_key, reveal))
+			.field(
+				"installation_token",
+				&Secret(&self.installation_token, reveal),
+			)
+			.field("bunq_public_key", &self.bunq_public_key)
+			.finish()
+	}
+}
+
+impl Registered {
+	/// Returns a value whose `Debug` output includes the secrets.
+	///
+	/// ```
+	/// use bunqers::client_builder::Registered;
+	/// use openssl::{pkey::PKey, rsa::Rsa};
+	///
+	/// let rsa = Rsa::generate(2048).unwrap();
+	/// let public_key = PKey::public_key_from_pem(&rsa.public_key_to_pem().unwrap()).unwrap();
+	/// let registered = Registered {
+	///     registered_device_id: 42.into(),
+	///     bunq_api_key: "api-key".into(),
+	///     installation_token: "installation-token".into(),
+	///     bunq_public_key: public_key,
+	/// };
+	///
+	/// assert!(!format!("{registered:?}").contains("api-key"));
+	/// assert!(format!("{:?}", registered.reveal()).contains("api-key"));
+	/// ```
+	pub fn reveal(&self) -> Revealed<'_, Self> {
+		Revealed(self)
+	}
+
+	fn fmt_debug(&self, f: &mut fmt::Formatter<'_>, reveal: bool) -> fmt::Result {
+		f.debug_struct("Registered")
+			.field("registered_device_id", &self.registered_device_id)
+			.field("bunq_api_key", &Secret(&self.bunq_api_key, reveal))
+			.field(
+				"installation_token",
+				&Secret(&self.installation_token, reveal),
+			)
+			.field("bunq_public_key", &self.bunq_public_key)
+			.finish()
+	}
+}
+
+impl Installed {
+	/// Returns a value whose `Debug` output includes the secrets.
+	pub fn reveal(&self) -> Revealed<'_, Self> {
+		Revealed(self)
+	}
+
+	fn fmt_debug(&self, f: &mut fmt::Formatter<'_>, reveal: bool) -> fmt::Result {
+		f.debug_struct("Installed")
+			.field(
+				"installation_token",
+				&Secret(&self.installation_token, reveal),
+			)
+			.field("bunq_public_key", &self.bunq_public_key)
+			.finish()
+	}
+}
+
+// The `Debug` output of the builder states masks tokens and API keys, so
+// they do not end up in logs by accident.
+impl fmt::Debug for UncheckedSession {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.fmt_debug(f, false)
+	}
+}
+
+impl fmt::Debug for Registered {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.fmt_debug(f, false)
+	}
+}
+
+impl fmt::Debug for Installed {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.fmt_debug(f, false)
+	}
+}
+
+/// Shows the secrets of a builder state or other credentials in its `Debug`
+/// output, see e.g. [`Registered::reveal`]. Only use it for deliberate
+/// inspection.
+pub struct Revealed<'a, T>(pub(crate) &'a T);
+
+impl fmt::Debug for Revealed<'_, UncheckedSession> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt_debug(f, true)
+	}
+}
+
+impl fmt::Debug for Revealed<'_, Registered> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt_debug(f, true)
+	}
+}
+
+impl fmt::Debug for Revealed<'_, Installed> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt_debug(f, true)
+	}
+}
+
+/// A secret field in `Debug` output, masked unless revealed.
+pub(crate) struct Secret<'a, T: ?Sized = str>(pub(crate) &'a T, pub(crate) bool);
+
+impl<'a, T> Secret<'a, T> {
+	/// Masks an optional secret, leaving visible whether it is set.
+	pub(crate) fn optional(value: &'a Option<T>, reveal: bool) -> Option<Self> {
+		value.as_ref().map(|value| Self(value, reveal))
+	}
+}
+
+impl<T: fmt::Debug + ?Sized> fmt::Debug for Secret<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Secret(value, true) => fmt::Debug::fmt(value, f),
+			Secret(_, false) => f.write_str("<redacted>"),
+		}
+	}
 }
 
 /// Typestate builder for constructing a [`Client`].
@@ -111,13 +289,168 @@ pub struct Installed {
 /// The type parameter `T` represents the current builder state. See the
 /// [module-level documentation](self) for the full state diagram.
 pub struct ClientBuilder<T> {
-	pub private_key: PKey<Private>,
+	pub private_key: PrivateKey,
 	pub api_base_url: String,
 	pub app_name: String,
 	messenger: Messenger,
+	pacing: Pacing,
+	/// When the previous setup step of this builder finished.
+	last_step: Option<Instant>,
 	pub context: T,
 }
 
+/// Base URL of the live Bunq API.
+pub const PRODUCTION_API_URL: &str = "https://api.bunq.com/v1";
+
+/// Base URL of the public Bunq sandbox.
+pub const SANDBOX_API_URL: &str = "https://public-api.sandbox.bunq.com/v1";
+
+/// Host of the live Bunq API, without an API version.
+pub const PRODUCTION_HOST: &str = "https://api.bunq.com";
+
+/// Host of the public Bunq sandbox, without an API version.
+pub const SANDBOX_HOST: &str = "https://public-api.sandbox.bunq.com";
+
+/// A version of the Bunq API, selected by the first segment of the path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum ApiVersion {
+	/// `/v1`, the only version Bunq offers so far.
+	#[default]
+	V1,
+}
+
+impl ApiVersion {
+	/// Returns the path segment of this version, e.g. `"v1"`.
+	pub fn path_segment(self) -> &'static str {
+		match self {
+			ApiVersion::V1 => "v1",
+		}
+	}
+}
+
+impl fmt::Display for ApiVersion {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.path_segment())
+	}
+}
+
+/// The Bunq environment a [`ClientBuilder`] talks to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Environment {
+	/// The live API, operating on real money.
+	Production,
+	/// The public sandbox, for development and tests.
+	Sandbox,
+	/// Any other base URL, including the API version, e.g. a mock server.
+	Custom(String),
+}
+
+impl Environment {
+	/// Returns the base URL of the default [`ApiVersion`] in this
+	/// environment.
+	///
+	/// ```
+	/// use bunqers::client_builder::Environment;
+	///
+	/// assert_eq!(Environment::Sandbox.base_url(), bunqers::SANDBOX_API_URL);
+	/// ```
+	pub fn base_url(&self) -> &str {
+		match self {
+			Environment::Production => PRODUCTION_API_URL,
+			Environment::Sandbox => SANDBOX_API_URL,
+			Environment::Custom(base_url) => base_url,
+		}
+	}
+
+	/// Returns the base URL of `version` of the API in this environment. A
+	/// [`Custom`](Environment::Custom) URL already names its version and is
+	/// returned as it is.
+	///
+	/// ```
+	/// use bunqers::client_builder::{ApiVersion, Environment};
+	///
+	/// assert_eq!(
+	///     Environment::Production.api_url(ApiVersion::V1),
+	///     bunqers::PRODUCTION_API_URL
+	/// );
+	/// ```
+	pub fn api_url(&self, version: ApiVersion) -> String {
+		match self {
+			Environment::Production => format!("{PRODUCTION_HOST}/{version}"),
+			Environment::Sandbox => format!("{SANDBOX_HOST}/{version}"),
+			Environment::Custom(base_url) => base_url.clone(),
+		}
+	}
+}
+
+/// How long a [`ClientBuilder`] waits between the steps of the setup.
+///
+/// Bunq throttles `/installation`, `/device-server` and `/session-server`, so
+/// running the steps back to back can get them rejected. By default the
+/// builder keeps [`Pacing::DEFAULT_DELAY`] between steps, except against the
+/// sandbox:
+///
+/// ```
+/// use bunqers::client_builder::Pacing;
+///
+/// assert_eq!(
+///     Pacing::default_for(bunqers::PRODUCTION_API_URL),
+///     Pacing::Delay(Pacing::DEFAULT_DELAY)
+/// );
+/// assert_eq!(Pacing::default_for(bunqers::SANDBOX_API_URL), Pacing::Off);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pacing {
+	/// Run each step right after the previous one.
+	Off,
+	/// Start each step at least this long after the previous one finished.
+	Delay(Duration),
+}
+
+impl Pacing {
+	/// The delay used against the production API.
+	pub const DEFAULT_DELAY: Duration = Duration::from_secs(3);
+
+	/// Returns the default pacing for `api_base_url`: off for the sandbox,
+	/// [`DEFAULT_DELAY`](Pacing::DEFAULT_DELAY) otherwise.
+	pub fn default_for(api_base_url: &str) -> Self {
+		if api_base_url.starts_with(SANDBOX_HOST) {
+			Pacing::Off
+		} else {
+			Pacing::Delay(Self::DEFAULT_DELAY)
+		}
+	}
+}
+
+/// Checks that `api_base_url` is an absolute `http(s)` URL without query or
+/// fragment, and returns it without trailing slashes, since endpoints are
+/// appended as `{base}/{endpoint}`.
+fn validate_base_url(api_base_url: &str) -> Result<String, BuildErrorReason> {
+	let invalid = || BuildErrorReason::InvalidBaseUrl(api_base_url.to_string());
+
+	let url = Url::parse(api_base_url).map_err(|_| invalid())?;
+	if !matches!(url.scheme(), "https" | "http")
+		|| url.host().is_none()
+		|| url.query().is_some()
+		|| url.fragment().is_some()
+	{
+		return Err(invalid());
+	}
+
+	Ok(api_base_url.trim_end_matches('/').to_string())
+}
+
+/// Returns `"{app_name} on {hostname}"`, or just the app name if the hostname
+/// cannot be determined, as is always the case on `wasm32`.
+fn default_device_description(app_name: &str) -> String {
+	#[cfg(not(target_arch = "wasm32"))]
+	if let Ok(hostname) = hostname::get() {
+		return format!("{app_name} on {}", hostname.to_string_lossy());
+	}
+	app_name.to_string()
+}
+
 /// An error returned when a builder state transition fails.
 #[derive(Debug)]
 pub struct BuildError<T> {
@@ -131,53 +464,544 @@ pub struct BuildError<T> {
 /// Reasons a [`ClientBuilder`] state transition can fail.
 #[derive(Debug)]
 pub enum BuildErrorReason {
-	/// OpenSSL failed to generate or wrap an RSA key pair.
-	KeyCreationError(ErrorStack),
-	/// OpenSSL failed to serialise a key to PEM.
-	KeySerialization(ErrorStack),
-	/// OpenSSL failed to parse a PEM-encoded key received from Bunq.
-	KeyDeserializationError(ErrorStack),
+	/// The API base URL is not an absolute `http(s)` URL.
+	InvalidBaseUrl(String),
+	/// An RSA key pair could not be generated or wrapped.
+	KeyCreationError(KeyError),
+	/// A key could not be serialised to PEM.
+	KeySerialization(KeyError),
+	/// A PEM-encoded key received from Bunq could not be parsed.
+	KeyDeserializationError(KeyError),
+	/// The private key passed to the builder is not a valid RSA key.
+	InvalidPrivateKey(KeyError),
+	/// The private key file could not be read.
+	KeyFileError(io::Error),
 	/// The HTTP request could not be built or sent.
 	BunqRequestError,
-	/// The response from Bunq could not be parsed.
+	/// The request could not be sent, or the response from Bunq could not be
+	/// read, parsed or verified.
 	BunqInvalidResponse(MessageError),
 	/// Bunq returned an API-level error response.
 	BunqResponseApiError(ApiErrorResponse),
+	/// An input was rejected before sending it to Bunq.
+	InvalidInput(ValidationError),
+}
+
+impl BuildErrorReason {
+	/// Returns the error Bunq responded with, if the step failed because of
+	/// an API error rather than e.g. a network problem.
+	///
+	/// Use [`ApiErrorResponse::kind`] to tell apart an incorrect API key, an
+	/// IP address that is not permitted, an expired session, and so on.
+	pub fn api_error(&self) -> Option<&ApiErrorResponse> {
+		match self {
+			BuildErrorReason::BunqResponseApiError(error) => Some(error),
+			_ => None,
+		}
+	}
+
+	/// Returns the error of the HTTP layer, if the request could not be sent
+	/// or its response could not be read.
+	pub fn message_error(&self) -> Option<&MessageError> {
+		match self {
+			BuildErrorReason::BunqInvalidResponse(error) => Some(error),
+			_ => None,
+		}
+	}
+
+	/// Returns `true` if Bunq rejected the credentials the step was sent
+	/// with, i.e. they are unknown, expired or not sufficient.
+	fn rejects_credentials(&self) -> bool {
+		self.api_error().is_some_and(|error| {
+			matches!(
+				error.kind(),
+				BunqErrorKind::InsufficientAuthentication
+					| BunqErrorKind::SessionExpired
+					| BunqErrorKind::NotFound
+			)
+		})
+	}
+}
+
+impl fmt::Display for BuildErrorReason {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			BuildErrorReason::InvalidBaseUrl(api_base_url) => {
+				write!(f, "invalid API base URL: {api_base_url}")
+			}
+			BuildErrorReason::KeyCreationError(_) => write!(f, "failed to create RSA key pair"),
+			BuildErrorReason::KeySerialization(_) => write!(f, "failed to serialize key to PEM"),
+			BuildErrorReason::KeyDeserializationError(_) => {
+				write!(f, "failed to parse PEM key received from Bunq")
+			}
+			BuildErrorReason::InvalidPrivateKey(_) => write!(f, "invalid private key"),
+			BuildErrorReason::KeyFileError(error) => {
+				write!(f, "failed to read private key file: {error}")
+			}
+			BuildErrorReason::BunqRequestError => write!(f, "failed to send request to Bunq"),
+			BuildErrorReason::BunqInvalidResponse(error) => {
+				write!(f, "invalid response from Bunq: {error}")
+			}
+			BuildErrorReason::BunqResponseApiError(error) => write!(f, "{error}"),
+			BuildErrorReason::InvalidInput(error) => write!(f, "invalid input: {error}"),
+		}
+	}
+}
+
+impl Error for BuildErrorReason {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			BuildErrorReason::KeyCreationError(error)
+			| BuildErrorReason::KeySerialization(error)
+			| BuildErrorReason::KeyDeserializationError(error)
+			| BuildErrorReason::InvalidPrivateKey(error) => Some(error),
+			BuildErrorReason::KeyFileError(error) => Some(error),
+			BuildErrorReason::InvalidBaseUrl(_) | BuildErrorReason::BunqRequestError => None,
+			BuildErrorReason::BunqInvalidResponse(error) => Some(error),
+			BuildErrorReason::BunqResponseApiError(error) => Some(error),
+			BuildErrorReason::InvalidInput(error) => Some(error),
+		}
+	}
+}
+
+impl<T> fmt::Display for BuildError<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "client builder step failed: {}", self.reason)
+	}
+}
+
+impl<T: fmt::Debug> Error for BuildError<T> {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		Some(&self.reason)
+	}
+}
+
+/// A [`BuildError`] with its context type erased, so errors from different
+/// builder steps can be propagated with `?` as one type.
+///
+/// The context can still be recovered with
+/// [`downcast`](Box::downcast), e.g. `error.context.downcast::<Registered>()`.
+#[derive(Debug)]
+pub struct BoxedBuildError {
+	pub reason: BuildErrorReason,
+	pub context: Box<dyn Any + Send + Sync>,
+}
+
+impl<T: Any + Send + Sync> From<BuildError<T>> for BoxedBuildError {
+	fn from(error: BuildError<T>) -> Self {
+		Self {
+			reason: error.reason,
+			context: Box::new(error.context),
+		}
+	}
+}
+
+impl fmt::Display for BoxedBuildError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "client builder step failed: {}", self.reason)
+	}
+}
+
+impl Error for BoxedBuildError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		Some(&self.reason)
+	}
+}
+
+/// An error returned by [`ClientBuilder::authenticate`], telling which step
+/// of the setup failed. Each variant keeps the [`BuildError`] of that step,
+/// including the context reached so far.
+#[derive(Debug)]
+pub enum AuthenticationError {
+	/// `POST /installation` failed.
+	Install(BuildError<()>),
+	/// `POST /device-server` failed.
+	Register(BuildError<Installed>),
+	/// `POST /session-server` failed.
+	CreateSession(BuildError<Registered>),
+	/// The new session was created but rejected by `GET /user`.
+	CheckSession(BuildError<UncheckedSession>),
+}
+
+impl AuthenticationError {
+	/// Returns the step that failed.
+	pub fn stage(&self) -> FlowStage {
+		match self {
+			AuthenticationError::Install(_) => FlowStage::Install,
+			AuthenticationError::Register(_) => FlowStage::Register,
+			AuthenticationError::CreateSession(_) => FlowStage::Session,
+			AuthenticationError::CheckSession(_) => FlowStage::Check,
+		}
+	}
+
+	/// Returns the reason the failing step gave.
+	pub fn reason(&self) -> &BuildErrorReason {
+		match self {
+			AuthenticationError::Install(error) => &error.reason,
+			AuthenticationError::Register(error) => &error.reason,
+			AuthenticationError::CreateSession(error) => &error.reason,
+			AuthenticationError::CheckSession(error) => &error.reason,
+		}
+	}
+}
+
+impl fmt::Display for AuthenticationError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"authentication failed while {}: {}",
+			self.stage(),
+			self.reason()
+		)
+	}
+}
+
+impl Error for AuthenticationError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		Some(self.reason())
+	}
+}
+
+/// A step of the Bunq setup, see [`FlowError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowStage {
+	/// Creating the key pair and calling `POST /installation`.
+	Install,
+	/// Calling `POST /device-server`.
+	Register,
+	/// Calling `POST /session-server`, including loading the stored keys the
+	/// session is created with.
+	Session,
+	/// Checking an existing session with `GET /user`.
+	Check,
+}
+
+impl fmt::Display for FlowStage {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let step = match self {
+			FlowStage::Install => "installing the device",
+			FlowStage::Register => "registering the device",
+			FlowStage::Session => "creating a session",
+			FlowStage::Check => "checking the session",
+		};
+		f.write_str(step)
+	}
+}
+
+/// Error returned by the high-level setup flows such as
+/// [`install_device`](crate::install_device) and
+/// [`create_client`](crate::create_client).
+///
+/// Tells which step failed and why, e.g. to log it:
+///
+/// ```
+/// use bunqers::client_builder::{BuildErrorReason, FlowError, FlowStage};
+///
+/// let error = FlowError {
+///     stage: FlowStage::Register,
+///     source: BuildErrorReason::BunqRequestError,
+/// };
+/// assert_eq!(
+///     error.to_string(),
+///     "setup failed while registering the device: failed to send request to Bunq"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct FlowError {
+	/// The step that failed.
+	pub stage: FlowStage,
+	/// Why the step failed, including the error Bunq responded with.
+	pub source: BuildErrorReason,
+}
+
+impl FlowError {
+	/// Returns a function that turns a [`BuildError`] of `stage` into a
+	/// `FlowError`, for use with `map_err`.
+	pub(crate) fn at<T>(stage: FlowStage) -> impl FnOnce(BuildError<T>) -> Self {
+		move |error| FlowError {
+			stage,
+			source: error.reason,
+		}
+	}
+}
+
+impl From<AuthenticationError> for FlowError {
+	fn from(error: AuthenticationError) -> Self {
+		let stage = error.stage();
+		let source = match error {
+			AuthenticationError::Install(error) => error.reason,
+			AuthenticationError::Register(error) => error.reason,
+			AuthenticationError::CreateSession(error) => error.reason,
+			AuthenticationError::CheckSession(error) => error.reason,
+		};
+		FlowError { stage, source }
+	}
+}
+
+impl fmt::Display for FlowError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "setup failed while {}: {}", self.stage, self.source)
+	}
+}
+
+impl Error for FlowError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		Some(&self.source)
+	}
+}
+
+impl<T> ClientBuilder<T> {
+	/// Uses `http_client` for all requests of this builder and of the
+	/// [`Client`] it builds, instead of a default `reqwest::Client`.
+	///
+	/// Configure timeouts, proxies or TLS settings on the `reqwest::Client`:
+	///
+	/// ```
+	/// use std::time::Duration;
+	///
+	/// use bunqers::{SANDBOX_API_URL, client_builder::ClientBuilder};
+	///
+	/// let http_client = reqwest::Client::builder()
+	///     .timeout(Duration::from_secs(10))
+	///     .build()
+	///     .unwrap();
+	/// let builder = ClientBuilder::new_without_key(SANDBOX_API_URL, "my-app")
+	///     .unwrap()
+	///     .with_http_client(http_client);
+	/// ```
+	pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+		self.messenger.set_http_client(http_client);
+		self
+	}
+
+	/// Sets the `User-Agent` header for all requests of this builder and of
+	/// the [`Client`] it builds. Defaults to the app name followed by
+	/// [`DEFAULT_USER_AGENT`](crate::messenger::DEFAULT_USER_AGENT), e.g.
+	/// `"my-app bunqers/0.1.0"`.
+	///
+	/// Returns [`ValidationError::InvalidHeaderValue`] if `user_agent` cannot
+	/// be sent in a header.
+	pub fn with_user_agent(
+		mut self,
+		user_agent: impl Into<String>,
+	) -> Result<Self, ValidationError> {
+		self.messenger.set_user_agent(user_agent)?;
+		Ok(self)
+	}
+
+	/// Sets the location sent as `X-Bunq-Geolocation` with every request of
+	/// this builder and of the [`Client`] it builds, or stops sending the
+	/// header with `None`. Defaults to [`Geolocation::unknown`].
+	pub fn with_geolocation(mut self, geolocation: Option<Geolocation>) -> Self {
+		self.messenger.set_geolocation(geolocation);
+		self
+	}
+
+	/// Sets how long to wait between the setup steps, replacing the default
+	/// of [`Pacing::default_for`] the base URL.
+	///
+	/// ```
+	/// use bunqers::{
+	///     PRODUCTION_API_URL,
+	///     client_builder::{ClientBuilder, Pacing},
+	/// };
+	///
+	/// let builder = ClientBuilder::new_without_key(PRODUCTION_API_URL, "my-app")
+	///     .unwrap()
+	///     .with_pacing(Pacing::Off);
+	/// ```
+	pub fn with_pacing(mut self, pacing: Pacing) -> Self {
+		self.pacing = pacing;
+		self
+	}
+
+	/// Waits until the pacing delay has passed since the previous step.
+	async fn pace(&self) {
+		if let (Pacing::Delay(delay), Some(last_step)) = (self.pacing, self.last_step) {
+			Sleep::until(last_step + delay).await;
+		}
+	}
+
+	/// Returns the client's private key as a PKCS#8 PEM string, e.g. to store
+	/// it for the next run.
+	///
+	/// ```
+	/// use bunqers::{SANDBOX_API_URL, client_builder::ClientBuilder};
+	///
+	/// let builder = ClientBuilder::new_without_key(SANDBOX_API_URL, "my-app").unwrap();
+	/// let pem = builder.export_key_pem().unwrap();
+	///
+	/// let restored = ClientBuilder::with_key_pem(SANDBOX_API_URL, "my-app", &pem).unwrap();
+	/// assert!(restored.private_key.public_eq(&builder.private_key));
+	/// ```
+	pub fn export_key_pem(&self) -> Result<String, BuildErrorReason> {
+		let pem = crypto::private_key_to_pem_pkcs8(&self.private_key)
+			.map_err(BuildErrorReason::KeySerialization)?;
+		Ok(String::from_utf8(pem).expect("PEM output contained non-UTF-8 characters"))
+	}
 }
 
 impl ClientBuilder<()> {
 	/// Creates a builder using the provided RSA private key.
 	///
 	/// Use this when you already have a key from a previous run and want to
-	/// avoid generating a new one.
+	/// avoid generating a new one. Besides a [`PrivateKey`], any
+	/// [`SigningKey`] is accepted, e.g. one converted from PKCS#8 bytes.
+	///
+	/// Returns an error if `api_base_url` is not an absolute `http(s)` URL.
+	/// A trailing slash is removed:
+	///
+	/// ```
+	/// use bunqers::{SANDBOX_API_URL, client_builder::ClientBuilder};
+	/// use openssl::{pkey::PKey, rsa::Rsa};
+	///
+	/// let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+	/// let builder =
+	///     ClientBuilder::new_with_key(format!("{SANDBOX_API_URL}/"), "my-app", key.clone())
+	///         .unwrap();
+	/// assert_eq!(builder.api_base_url, SANDBOX_API_URL);
+	///
+	/// assert!(ClientBuilder::new_with_key("api.bunq.com/v1", "my-app", key).is_err());
+	/// ```
 	pub fn new_with_key(
-		api_base_url: String,
-		app_name: String,
-		private_key: PKey<Private>,
-	) -> Self {
-		Self {
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+		private_key: impl Into<SigningKey>,
+	) -> Result<Self, BuildError<()>> {
+		let api_base_url =
+			validate_base_url(&api_base_url.into()).map_err(|reason| BuildError {
+				reason,
+				context: (),
+			})?;
+		let app_name = app_name.into();
+		let private_key = private_key.into().into_pkey();
+
+		Ok(Self {
 			api_base_url: api_base_url.clone(),
 			app_name: app_name.clone(),
 			private_key: private_key.clone(),
 			context: (),
-			messenger: Messenger::new(api_base_url, app_name, private_key, None, None),
-		}
+			pacing: Pacing::default_for(&api_base_url),
+			last_step: None,
+			messenger: builder_messenger(api_base_url, app_name, private_key, None, None),
+		})
 	}
 
 	/// Creates a builder with a freshly generated 2048-bit RSA key pair.
 	///
-	/// Returns an error if OpenSSL fails to generate the key.
-	pub fn new_without_key(api_base_url: String, app_name: String) -> Result<Self, BuildError<()>> {
-		let new_key_pair = Rsa::generate(2048).map_err(|error| BuildError {
-			reason: BuildErrorReason::KeyCreationError(error),
+	/// Returns an error if `api_base_url` is invalid (see
+	/// [`new_with_key`](ClientBuilder::new_with_key)) or if the key cannot be
+	/// generated. Use [`new_with_key_spec`](ClientBuilder::new_with_key_spec)
+	/// for a larger key.
+	pub fn new_without_key(
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+	) -> Result<Self, BuildError<()>> {
+		Self::new_with_key_spec(api_base_url, app_name, KeySpec::default())
+	}
+
+	/// Creates a builder with a freshly generated key pair according to
+	/// `key_spec`.
+	///
+	/// Returns an error if `api_base_url` is invalid (see
+	/// [`new_with_key`](ClientBuilder::new_with_key)) or if the key cannot be
+	/// generated.
+	pub fn new_with_key_spec(
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+		key_spec: KeySpec,
+	) -> Result<Self, BuildError<()>> {
+		let api_base_url = api_base_url.into();
+		validate_base_url(&api_base_url).map_err(|reason| BuildError {
+			reason,
 			context: (),
 		})?;
-		let private_key = PKey::from_rsa(new_key_pair).map_err(|error| BuildError {
+
+		let private_key = SigningKey::generate(key_spec).map_err(|error| BuildError {
 			reason: BuildErrorReason::KeyCreationError(error),
 			context: (),
 		})?;
 
-		Ok(Self::new_with_key(api_base_url, app_name, private_key))
+		Self::new_with_key(api_base_url, app_name, private_key)
+	}
+
+	/// Creates a builder using an RSA private key in PEM format, either PKCS#8
+	/// (`BEGIN PRIVATE KEY`) or PKCS#1 (`BEGIN RSA PRIVATE KEY`).
+	///
+	/// Returns an error if `api_base_url` is invalid or the key cannot be
+	/// parsed.
+	pub fn with_key_pem(
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+		pem: &str,
+	) -> Result<Self, BuildError<()>> {
+		let private_key =
+			crypto::private_key_from_pem(pem.as_bytes()).map_err(|error| BuildError {
+				reason: BuildErrorReason::InvalidPrivateKey(error),
+				context: (),
+			})?;
+		Self::new_with_key(api_base_url, app_name, private_key)
+	}
+
+	/// Creates a builder using a DER-encoded RSA private key, either PKCS#8 or
+	/// PKCS#1.
+	///
+	/// Returns an error if `api_base_url` is invalid or the key cannot be
+	/// parsed.
+	pub fn with_key_der(
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+		der: &[u8],
+	) -> Result<Self, BuildError<()>> {
+		let private_key = crypto::private_key_from_der(der).map_err(|error| BuildError {
+			reason: BuildErrorReason::InvalidPrivateKey(error),
+			context: (),
+		})?;
+		Self::new_with_key(api_base_url, app_name, private_key)
+	}
+
+	/// Creates a builder using the PEM-encoded RSA private key stored at
+	/// `path`. See [`with_key_pem`](ClientBuilder::with_key_pem).
+	pub fn with_key_file(
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+		path: impl AsRef<Path>,
+	) -> Result<Self, BuildError<()>> {
+		let pem = fs::read_to_string(path).map_err(|error| BuildError {
+			reason: BuildErrorReason::KeyFileError(error),
+			context: (),
+		})?;
+		Self::with_key_pem(api_base_url, app_name, &pem)
+	}
+
+	/// Creates a builder for the production API with a freshly generated key
+	/// pair.
+	pub fn production(app_name: impl Into<String>) -> Result<Self, BuildError<()>> {
+		Self::new_for_environment(Environment::Production, app_name)
+	}
+
+	/// Creates a builder for the sandbox API with a freshly generated key
+	/// pair.
+	pub fn sandbox(app_name: impl Into<String>) -> Result<Self, BuildError<()>> {
+		Self::new_for_environment(Environment::Sandbox, app_name)
+	}
+
+	/// Creates a builder for `environment` with a freshly generated key pair.
+	pub fn new_for_environment(
+		environment: Environment,
+		app_name: impl Into<String>,
+	) -> Result<Self, BuildError<()>> {
+		Self::new_for_api_version(environment, ApiVersion::default(), app_name)
+	}
+
+	/// Creates a builder for `version` of the API in `environment` with a
+	/// freshly generated key pair.
+	pub fn new_for_api_version(
+		environment: Environment,
+		version: ApiVersion,
+		app_name: impl Into<String>,
+	) -> Result<Self, BuildError<()>> {
+		Self::new_without_key(environment.api_url(version), app_name)
 	}
 
 	/// Calls the Bunq `/installation` endpoint to exchange public keys.
@@ -190,12 +1014,11 @@ impl ClientBuilder<()> {
 	pub async fn install_device(self) -> Result<ClientBuilder<Installed>, BuildError<()>> {
 		let body = CreateInstallation {
 			client_public_key: String::from_utf8_lossy(
-				&self
-					.private_key
-					.public_key_to_pem()
+				&crypto::public_key(&self.private_key)
+					.and_then(|key| crypto::public_key_to_pem(&key))
 					.map_err(|error| BuildError {
 						reason: BuildErrorReason::KeySerialization(error),
-						context: self.context.clone(),
+						context: (),
 					})?,
 			)
 			.to_string(),
@@ -203,36 +1026,30 @@ impl ClientBuilder<()> {
 
 		let body_text = serde_json::to_string(&body).map_err(|_| BuildError {
 			reason: BuildErrorReason::BunqRequestError,
-			context: self.context.clone(),
+			context: (),
 		})?;
 
 		// Use send_unverified because we do not yet have Bunq's public key.
-		let response: ApiResponse<Installation> = self
+		let response: BunqResponse<Installation> = self
 			.messenger
 			.send_unverified(Method::POST, "installation", Some(body_text))
 			.await
 			.map_err(|error| BuildError {
 				reason: BuildErrorReason::BunqInvalidResponse(error),
-				context: self.context.clone(),
+				context: (),
 			})?;
 
 		let result = response.into_result().map_err(|error| BuildError {
 			reason: BuildErrorReason::BunqResponseApiError(error),
-			context: self.context.clone(),
+			context: (),
 		})?;
 
 		// Parse Bunq's public key from the response.
-		let bunq_public_key =
-			Rsa::public_key_from_pem(result.bunq_public_key.as_bytes()).map_err(|error| {
-				BuildError {
-					reason: BuildErrorReason::KeyDeserializationError(error),
-					context: self.context.clone(),
-				}
+		let bunq_public_key = crypto::public_key_from_pem(result.bunq_public_key.as_bytes())
+			.map_err(|error| BuildError {
+				reason: BuildErrorReason::KeyDeserializationError(error),
+				context: (),
 			})?;
-		let bunq_public_key = PKey::from_rsa(bunq_public_key).map_err(|error| BuildError {
-			reason: BuildErrorReason::KeyDeserializationError(error),
-			context: self.context.clone(),
-		})?;
 
 		// From now on, sign requests with the installation token and verify
 		// responses with Bunq's public key.
@@ -246,12 +1063,220 @@ impl ClientBuilder<()> {
 			app_name: self.app_name,
 			private_key: self.private_key,
 			messenger,
+			pacing: self.pacing,
+			last_step: Some(Instant::now()),
 			context: Installed {
 				installation_token,
 				bunq_public_key,
 			},
 		})
 	}
+
+	/// Runs the full setup in one call: installs the device, registers it with
+	/// `bunq_api_key` and creates a session.
+	///
+	/// Use [`new_without_key`](ClientBuilder::new_without_key) or
+	/// [`new_with_key`](ClientBuilder::new_with_key) to choose the key first.
+	/// On failure the error tells which step failed.
+	///
+	/// ```rust,no_run
+	/// use bunqers::client_builder::ClientBuilder;
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// let client = ClientBuilder::new_without_key(bunqers::PRODUCTION_API_URL, "my-app")?
+	///     .authenticate("your-api-key", "my-device")
+	///     .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn authenticate(
+		self,
+		bunq_api_key: impl Into<String>,
+		device_description: impl Into<String>,
+	) -> Result<Client, AuthenticationError> {
+		self.authenticate_with_hook(bunq_api_key.into(), &device_description.into(), &mut |_| {})
+			.await
+	}
+
+	/// Resumes from a stored [`SessionState`], falling back one step at a time
+	/// until a [`Client`] can be built:
+	///
+	/// 1. reuse the stored session token,
+	/// 2. create a new session for the stored device registration,
+	/// 3. register the device again,
+	/// 4. install the device again with the stored key,
+	/// 5. start over with a new key.
+	///
+	/// A step is only skipped when Bunq rejects the stored credentials it
+	/// used, i.e. with [`BunqErrorKind::InsufficientAuthentication`],
+	/// [`BunqErrorKind::SessionExpired`] or [`BunqErrorKind::NotFound`]. Any
+	/// other failure, such as a network error, a rate limit or a server error,
+	/// is returned right away, so valid stored credentials are never replaced
+	/// because of a temporary problem.
+	///
+	/// `on_state_change` is called with the new state after every step that
+	/// changed it, so it can be persisted before the next step runs. A stored
+	/// registration for a different `bunq_api_key` is not reused.
+	///
+	/// ```rust,no_run
+	/// use bunqers::{client_builder::ClientBuilder, session_state::SessionState};
+	///
+	/// # #[tokio::main]
+	/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+	/// let state = SessionState::load("context.json").unwrap_or_default();
+	/// let client = ClientBuilder::resume(
+	///     state,
+	///     bunqers::PRODUCTION_API_URL,
+	///     "my-app",
+	///     "your-api-key",
+	///     "my-device",
+	///     |state| state.save("context.json").expect("Failed to store context"),
+	/// )
+	/// .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub async fn resume(
+		state: SessionState,
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+		bunq_api_key: impl Into<String>,
+		device_description: impl Into<String>,
+		mut on_state_change: impl FnMut(&SessionState),
+	) -> Result<Client, AuthenticationError> {
+		let api_base_url: String = api_base_url.into();
+		let app_name: String = app_name.into();
+		let bunq_api_key: String = bunq_api_key.into();
+		let device_description: String = device_description.into();
+		let device_description = device_description.as_str();
+
+		let mut state = state;
+		if state
+			.bunq_api_key
+			.as_ref()
+			.is_some_and(|stored_api_key| *stored_api_key != bunq_api_key)
+		{
+			state.bunq_api_key = None;
+			state.registered_device_id = None;
+			state.session_token = None;
+			state.owner_id = None;
+		}
+
+		let Some((stage, private_key)) = state.into_stage() else {
+			return Self::new_without_key(api_base_url, app_name)
+				.map_err(AuthenticationError::Install)?
+				.authenticate_with_hook(bunq_api_key, device_description, &mut on_state_change)
+				.await;
+		};
+
+		let mut registered = None;
+		let mut installed = None;
+		match stage {
+			SessionStage::Session(unchecked_session) => {
+				match ClientBuilder::from_unchecked_session(
+					unchecked_session,
+					api_base_url.clone(),
+					app_name.clone(),
+					private_key.clone(),
+				)
+				.check_session()
+				.await
+				{
+					Ok(builder) => return Ok(builder.build()),
+					Err(error) if error.reason.rejects_credentials() => {
+						registered = Some(error.context.into())
+					}
+					Err(error) => return Err(AuthenticationError::CheckSession(error)),
+				}
+			}
+			SessionStage::Registration(registration) => registered = Some(registration),
+			SessionStage::Installation(installation) => installed = Some(installation),
+			SessionStage::KeyOnly => {}
+		}
+
+		if let Some(registered) = registered {
+			match ClientBuilder::from_registration(
+				registered,
+				api_base_url.clone(),
+				app_name.clone(),
+				private_key.clone(),
+			)
+			.create_session()
+			.await
+			{
+				Ok(builder) => {
+					on_state_change(&SessionState::from_session(
+						&builder.context,
+						&builder.private_key,
+					));
+					return Ok(builder.build());
+				}
+				Err(error) if error.reason.rejects_credentials() => {
+					installed = Some(error.context.into())
+				}
+				Err(error) => return Err(AuthenticationError::CreateSession(error)),
+			}
+		}
+
+		if let Some(installed) = installed {
+			let result = ClientBuilder::from_installation(
+				installed,
+				api_base_url.clone(),
+				app_name.clone(),
+				private_key.clone(),
+			)
+			.finish_setup(
+				bunq_api_key.clone(),
+				device_description,
+				&mut on_state_change,
+			)
+			.await;
+			match result {
+				Err(error) if error.reason().rejects_credentials() => {}
+				result => return result,
+			}
+		}
+
+		let result = Self::new_with_key(api_base_url.clone(), app_name.clone(), private_key)
+			.map_err(AuthenticationError::Install)?
+			.authenticate_with_hook(
+				bunq_api_key.clone(),
+				device_description,
+				&mut on_state_change,
+			)
+			.await;
+		match result {
+			Err(error) if error.reason().rejects_credentials() => {}
+			result => return result,
+		}
+
+		Self::new_without_key(api_base_url, app_name)
+			.map_err(AuthenticationError::Install)?
+			.authenticate_with_hook(bunq_api_key, device_description, &mut on_state_change)
+			.await
+	}
+
+	/// Like [`authenticate`](ClientBuilder::authenticate), but reports the
+	/// state after each step to `on_state_change`.
+	async fn authenticate_with_hook(
+		self,
+		bunq_api_key: String,
+		device_description: &str,
+		on_state_change: &mut impl FnMut(&SessionState),
+	) -> Result<Client, AuthenticationError> {
+		let builder = self
+			.install_device()
+			.await
+			.map_err(AuthenticationError::Install)?;
+		on_state_change(&SessionState::from_installation(
+			&builder.context,
+			&builder.private_key,
+		));
+		builder
+			.finish_setup(bunq_api_key, device_description, on_state_change)
+			.await
+	}
 }
 
 impl ClientBuilder<Installed> {
@@ -261,15 +1286,20 @@ impl ClientBuilder<Installed> {
 	/// persisted [`crate::InstallationContext`] without a device ID.
 	pub fn from_installation(
 		context: Installed,
-		api_base_url: String,
-		app_name: String,
-		private_key: PKey<Private>,
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+		private_key: PrivateKey,
 	) -> Self {
+		let api_base_url: String = api_base_url.into();
+		let app_name: String = app_name.into();
+
 		Self {
 			api_base_url: api_base_url.clone(),
 			app_name: app_name.clone(),
 			private_key: private_key.clone(),
-			messenger: Messenger::new(
+			pacing: Pacing::default_for(&api_base_url),
+			last_step: None,
+			messenger: builder_messenger(
 				api_base_url,
 				app_name,
 				private_key,
@@ -288,12 +1318,20 @@ impl ClientBuilder<Installed> {
 	/// On success, advances the builder to the [`Registered`] state.
 	pub async fn register_device(
 		self,
-		bunq_api_key: String,
-		device_description: &str,
+		bunq_api_key: impl Into<String>,
+		device_description: impl Into<String>,
 	) -> Result<ClientBuilder<Registered>, BuildError<Installed>> {
+		let bunq_api_key: String = bunq_api_key.into();
+		let device_description: String = device_description.into();
+		validate_device_description(&device_description).map_err(|error| BuildError {
+			reason: BuildErrorReason::InvalidInput(error),
+			context: self.context.clone(),
+		})?;
+		self.pace().await;
+
 		let body = CreateDeviceServer {
 			bunq_api_key: &bunq_api_key,
-			description: device_description,
+			description: &device_description,
 			permitted_ips: Vec::new(),
 		};
 
@@ -302,7 +1340,7 @@ impl ClientBuilder<Installed> {
 			context: self.context.clone(),
 		})?;
 
-		let response: ApiResponse<Single<DeviceServerSmall>> = self
+		let response: BunqResponse<Single<DeviceServerSmall>> = self
 			.messenger
 			.send(Method::POST, "device-server", Some(body))
 			.await
@@ -310,12 +1348,9 @@ impl ClientBuilder<Installed> {
 				reason: BuildErrorReason::BunqInvalidResponse(error),
 				context: self.context.clone(),
 			})?;
-		let result = response.into_result().map_err(|error| {
-			println!(
-				"Status: {:?}, descriptions: {:?}",
-				error.status_code, error.reasons
-			);
-			panic!("Failed");
+		let result = response.into_result().map_err(|error| BuildError {
+			reason: BuildErrorReason::BunqResponseApiError(error),
+			context: self.context.clone(),
 		})?;
 		let registered_device_id = result.id;
 
@@ -324,6 +1359,8 @@ impl ClientBuilder<Installed> {
 			app_name: self.app_name,
 			private_key: self.private_key,
 			messenger: self.messenger,
+			pacing: self.pacing,
+			last_step: Some(Instant::now()),
 			context: Registered {
 				registered_device_id,
 				bunq_api_key,
@@ -332,6 +1369,46 @@ impl ClientBuilder<Installed> {
 			},
 		})
 	}
+
+	/// Like [`register_device`](ClientBuilder::register_device), but describes
+	/// the device as `"{app_name} on {hostname}"`.
+	///
+	/// Handy for quick scripts; production code should pass a description
+	/// that lets the user recognise the device in the app.
+	pub async fn register_device_with_defaults(
+		self,
+		bunq_api_key: impl Into<String>,
+	) -> Result<ClientBuilder<Registered>, BuildError<Installed>> {
+		let device_description = default_device_description(&self.app_name);
+		self.register_device(bunq_api_key, device_description).await
+	}
+
+	/// Registers the device and creates a session, reporting the state after
+	/// each step to `on_state_change`.
+	async fn finish_setup(
+		self,
+		bunq_api_key: String,
+		device_description: &str,
+		on_state_change: &mut impl FnMut(&SessionState),
+	) -> Result<Client, AuthenticationError> {
+		let builder = self
+			.register_device(bunq_api_key, device_description)
+			.await
+			.map_err(AuthenticationError::Register)?;
+		on_state_change(&SessionState::from_registration(
+			&builder.context,
+			&builder.private_key,
+		));
+		let builder = builder
+			.create_session()
+			.await
+			.map_err(AuthenticationError::CreateSession)?;
+		on_state_change(&SessionState::from_session(
+			&builder.context,
+			&builder.private_key,
+		));
+		Ok(builder.build())
+	}
 }
 
 impl ClientBuilder<Registered> {
@@ -341,15 +1418,20 @@ impl ClientBuilder<Registered> {
 	/// restoring from a persisted [`crate::InstallationContext`].
 	pub fn from_registration(
 		context: Registered,
-		api_base_url: String,
-		app_name: String,
-		private_key: PKey<Private>,
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+		private_key: PrivateKey,
 	) -> Self {
+		let api_base_url: String = api_base_url.into();
+		let app_name: String = app_name.into();
+
 		Self {
 			api_base_url: api_base_url.clone(),
 			app_name: app_name.clone(),
 			private_key: private_key.clone(),
-			messenger: Messenger::new(
+			pacing: Pacing::default_for(&api_base_url),
+			last_step: None,
+			messenger: builder_messenger(
 				api_base_url,
 				app_name,
 				private_key,
@@ -370,6 +1452,7 @@ impl ClientBuilder<Registered> {
 	pub async fn create_session(
 		self,
 	) -> Result<ClientBuilder<SessionContext>, BuildError<Registered>> {
+		self.pace().await;
 		let body = CreateSession {
 			bunq_api_key: self.context.bunq_api_key.clone(),
 		};
@@ -378,7 +1461,7 @@ impl ClientBuilder<Registered> {
 			context: self.context.clone(),
 		})?;
 
-		let response: ApiResponse<BunqSession> = self
+		let response: BunqResponse<BunqSession> = self
 			.messenger
 			.send(Method::POST, "session-server", Some(body))
 			.await
@@ -392,16 +1475,21 @@ impl ClientBuilder<Registered> {
 		})?;
 
 		let session_token = result.token.token;
+		let session_created_at = Some(result.token.created.and_utc());
+		let session_timeout = Some(result.user_person.session_timeout);
 		let owner_id = result.user_person.id;
 
 		let mut messenger = self.messenger;
 		messenger.set_authentication_token(Some(session_token.clone()));
+		messenger.set_session_lifetime(session_created_at, session_timeout);
 
 		Ok(ClientBuilder {
 			api_base_url: self.api_base_url,
 			app_name: self.app_name,
 			private_key: self.private_key,
 			messenger,
+			pacing: self.pacing,
+			last_step: Some(Instant::now()),
 			context: SessionContext {
 				owner_id,
 				session_token,
@@ -409,6 +1497,8 @@ impl ClientBuilder<Registered> {
 				bunq_api_key: self.context.bunq_api_key,
 				installation_token: self.context.installation_token,
 				bunq_public_key: self.context.bunq_public_key,
+				session_created_at,
+				session_timeout,
 			},
 		})
 	}
@@ -422,15 +1512,20 @@ impl ClientBuilder<UncheckedSession> {
 	/// verify the token is still accepted by the API.
 	pub fn from_unchecked_session(
 		context: UncheckedSession,
-		api_base_url: String,
-		app_name: String,
-		private_key: PKey<Private>,
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+		private_key: PrivateKey,
 	) -> Self {
+		let api_base_url: String = api_base_url.into();
+		let app_name: String = app_name.into();
+
 		Self {
 			api_base_url: api_base_url.clone(),
 			app_name: app_name.clone(),
 			private_key: private_key.clone(),
-			messenger: Messenger::new(
+			pacing: Pacing::default_for(&api_base_url),
+			last_step: None,
+			messenger: builder_messenger(
 				api_base_url,
 				app_name,
 				private_key,
@@ -448,7 +1543,8 @@ impl ClientBuilder<UncheckedSession> {
 	pub async fn check_session(
 		self,
 	) -> Result<ClientBuilder<SessionContext>, BuildError<UncheckedSession>> {
-		let response: Result<ApiResponse<Single<User>>, _> =
+		self.pace().await;
+		let response: Result<BunqResponse<Single<User>>, _> =
 			self.messenger.send(Method::GET, "user", None).await;
 
 		match response {
@@ -458,6 +1554,8 @@ impl ClientBuilder<UncheckedSession> {
 					app_name: self.app_name,
 					private_key: self.private_key,
 					messenger: self.messenger,
+					pacing: self.pacing,
+					last_step: Some(Instant::now()),
 					context: SessionContext {
 						owner_id: user.user_person.id,
 						session_token: self.context.session_token,
@@ -465,6 +1563,8 @@ impl ClientBuilder<UncheckedSession> {
 						bunq_api_key: self.context.bunq_api_key,
 						installation_token: self.context.installation_token,
 						bunq_public_key: self.context.bunq_public_key,
+						session_created_at: None,
+						session_timeout: None,
 					},
 				}),
 				Err(error) => Err(BuildError {
@@ -472,8 +1572,8 @@ impl ClientBuilder<UncheckedSession> {
 					context: self.context,
 				}),
 			},
-			Err(_) => Err(BuildError {
-				reason: BuildErrorReason::BunqRequestError,
+			Err(error) => Err(BuildError {
+				reason: BuildErrorReason::BunqInvalidResponse(error),
 				context: self.context,
 			}),
 		}
@@ -492,3 +1592,23 @@ impl ClientBuilder<SessionContext> {
 		}
 	}
 }
+
+/// Creates the messenger of a builder, or of a client restored with
+/// [`Client::from_session_context`], with the builder's defaults.
+pub(crate) fn builder_messenger(
+	base_url: String,
+	app_name: String,
+	private_sign_key: PrivateKey,
+	bunq_public_sign_key: Option<PublicKey>,
+	authentication_token: Option<String>,
+) -> Messenger {
+	let mut messenger = Messenger::new(
+		base_url,
+		app_name,
+		private_sign_key,
+		bunq_public_sign_key,
+		authentication_token,
+	);
+	messenger.set_geolocation(Some(Geolocation::unknown()));
+	messenger
+}
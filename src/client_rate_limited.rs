@@ -24,18 +24,13 @@
 //! # Example
 //!
 //! ```rust,no_run
-//! use std::{sync::Arc, time::Duration};
+//! use std::sync::Arc;
 //! use bunqers::client_rate_limited::ClientRateLimited;
-//! use ritlers::async_rt::RateLimiter;
 //!
 //! # #[tokio::main]
 //! # async fn main() {
 //! # let client: bunqers::client::Client = todo!();
-//! let client_rl = Arc::new(ClientRateLimited {
-//!     client,
-//!     ratelimiter_get:  RateLimiter::new(3, Duration::from_secs(1)).unwrap(),
-//!     ratelimiter_post: RateLimiter::new(1, Duration::from_secs(1)).unwrap(),
-//! });
+//! let client_rl = Arc::new(ClientRateLimited::new(client, 3));
 //!
 //! client_rl.get_user_ratelimited(|result| async move {
 //!     let response = result.expect("rate limit exhausted");
@@ -46,6 +41,7 @@
 //! ```
 
 use std::{
+	fmt,
 	future::Future,
 	pin::Pin,
 	sync::{Arc, atomic::AtomicU32},
@@ -53,9 +49,8 @@ use std::{
 };
 
 use ritlers::{TaskResult, async_rt::RateLimiter};
-use rust_decimal::Decimal;
 
-use crate::{client::Client, messenger::ApiResponse, types::*};
+use crate::{client::Client, messenger::BunqResponse, types::*, validation::ValidationError};
 
 /// A type-erased, heap-allocated future that resolves to `()`.
 ///
@@ -70,29 +65,54 @@ pub struct RateLimitExhausted {
 	pub retries: u32,
 }
 
+impl fmt::Display for RateLimitExhausted {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"Bunq kept rate limiting the request after {} retries",
+			self.retries
+		)
+	}
+}
+
+impl std::error::Error for RateLimitExhausted {}
+
 /// A type-erased callback invoked with the API response result.
 /// Receives `Err(RateLimitExhausted)` if all retries were exhausted.
-type OnResponse<T> = Arc<dyn Fn(Result<ApiResponse<T>, RateLimitExhausted>) -> BoxFuture + Send + Sync>;
+type OnResponse<R> = Arc<dyn Fn(Result<R, RateLimitExhausted>) -> BoxFuture + Send + Sync>;
 
 /// A type-erased closure that, when called, produces a future that fetches
 /// data from the API. Called repeatedly on retry.
-type FetchFn<T> =
-	Arc<dyn Fn() -> Pin<Box<dyn Future<Output = ApiResponse<T>> + Send + 'static>> + Send + Sync>;
+type FetchFn<R> = Arc<dyn Fn() -> Pin<Box<dyn Future<Output = R> + Send + 'static>> + Send + Sync>;
+
+/// The result of a fetch, which tells whether Bunq rate limited it.
+trait FetchResult {
+	fn is_rate_limited(&self) -> bool;
+}
+
+impl<T> FetchResult for BunqResponse<T> {
+	fn is_rate_limited(&self) -> bool {
+		BunqResponse::is_rate_limited(self)
+	}
+}
+
+/// A fetch that failed before anything was sent is never rate limited.
+impl<T, E> FetchResult for Result<BunqResponse<T>, E> {
+	fn is_rate_limited(&self) -> bool {
+		self.as_ref().is_ok_and(BunqResponse::is_rate_limited)
+	}
+}
 
 /// A [`Client`] with separate rate limiters for GET and POST/PUT requests.
 ///
-/// Construct this directly and wrap it in an [`Arc`] to share across tasks:
+/// Construct it with [`new`](ClientRateLimited::new) and wrap it in an
+/// [`Arc`] to share across tasks:
 ///
 /// ```rust,no_run
-/// # use std::{sync::Arc, time::Duration};
+/// # use std::sync::Arc;
 /// # use bunqers::client_rate_limited::ClientRateLimited;
-/// # use ritlers::async_rt::RateLimiter;
 /// # let client: bunqers::client::Client = todo!();
-/// let client_rl = Arc::new(ClientRateLimited {
-///     client,
-///     ratelimiter_get:  RateLimiter::new(3, Duration::from_secs(1)).unwrap(),
-///     ratelimiter_post: RateLimiter::new(1, Duration::from_secs(1)).unwrap(),
-/// });
+/// let client_rl = Arc::new(ClientRateLimited::new(client, 3));
 /// ```
 pub struct ClientRateLimited {
 	pub client: Client,
@@ -113,10 +133,10 @@ pub struct ClientRateLimited {
 /// a new Tokio task so the rate-limiter slot is freed immediately — either with
 /// `Ok(response)` on success or `Err(`[`RateLimitExhausted`]`)` once all
 /// retries are used up.
-async fn schedule<T: Send + 'static>(
+async fn schedule<R: FetchResult + Send + 'static>(
 	ratelimiter: &RateLimiter,
-	fetch: FetchFn<T>,
-	on_response: OnResponse<T>,
+	fetch: FetchFn<R>,
+	on_response: OnResponse<R>,
 	max_retries: u32,
 ) -> Duration {
 	let retries = Arc::new(AtomicU32::new(0));
@@ -152,17 +172,40 @@ async fn schedule<T: Send + 'static>(
 }
 
 impl ClientRateLimited {
+	/// Wraps `client` with rate limiters matching Bunq's documented
+	/// per-device quotas (see [`create_rate_limited_client`](crate::create_rate_limited_client)).
+	///
+	/// # Panics
+	///
+	/// Panics if the rate limiters cannot be initialised, which does not
+	/// happen for the fixed limits used here.
+	pub fn new(client: Client, max_retries: u32) -> Self {
+		ClientRateLimited {
+			client,
+			ratelimiter_get: RateLimiter::new(3, Duration::from_secs(3))
+				.expect("Failed to create GET rate limiter"),
+			ratelimiter_post: RateLimiter::new(5, Duration::from_secs(3))
+				.expect("Failed to create POST rate limiter"),
+			ratelimiter_put: RateLimiter::new(2, Duration::from_secs(3))
+				.expect("Failed to create PUT rate limiter"),
+			max_retries,
+		}
+	}
+
 	/// Fetches the user account associated with the current session.
 	///
 	/// `on_response` is called (on a spawned task) with `Ok(response)` on
 	/// success or `Err(`[`RateLimitExhausted`]`)` if all retries are used up.
 	pub async fn get_user_ratelimited<F, Fut>(self: &Arc<Self>, on_response: F) -> Duration
 	where
-		F: Fn(Result<ApiResponse<Single<User>>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
+		F: Fn(Result<BunqResponse<Single<User>>, RateLimitExhausted>) -> Fut
+			+ Send
+			+ Sync
+			+ 'static,
 		Fut: Future<Output = ()> + Send + 'static,
 	{
 		let c = Arc::clone(self);
-		let fetch: FetchFn<Single<User>> = Arc::new(move || {
+		let fetch: FetchFn<BunqResponse<Single<User>>> = Arc::new(move || {
 			let c = Arc::clone(&c);
 			Box::pin(async move { c.client.get_user().await })
 		});
@@ -184,14 +227,15 @@ impl ClientRateLimited {
 		on_response: F,
 	) -> Duration
 	where
-		F: Fn(Result<ApiResponse<Multiple<MonetaryAccountBankWrapper>>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
+		F: Fn(Result<BunqResponse<Multiple<MonetaryAccountBankWrapper>>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
 		Fut: Future<Output = ()> + Send + 'static,
 	{
 		let c = Arc::clone(self);
-		let fetch: FetchFn<Multiple<MonetaryAccountBankWrapper>> = Arc::new(move || {
-			let c = Arc::clone(&c);
-			Box::pin(async move { c.client.get_monetary_accounts().await })
-		});
+		let fetch: FetchFn<BunqResponse<Multiple<MonetaryAccountBankWrapper>>> =
+			Arc::new(move || {
+				let c = Arc::clone(&c);
+				Box::pin(async move { c.client.get_monetary_accounts().await })
+			});
 		schedule(
 			&self.ratelimiter_get,
 			fetch,
@@ -207,18 +251,19 @@ impl ClientRateLimited {
 	/// success or `Err(`[`RateLimitExhausted`]`)` if all retries are used up.
 	pub async fn get_monetary_account_ratelimited<F, Fut>(
 		self: &Arc<Self>,
-		bank_account_id: u32,
+		bank_account_id: MonetaryAccountId,
 		on_response: F,
 	) -> Duration
 	where
-		F: Fn(Result<ApiResponse<Single<MonetaryAccountBankWrapper>>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
+		F: Fn(Result<BunqResponse<Single<MonetaryAccountBankWrapper>>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
 		Fut: Future<Output = ()> + Send + 'static,
 	{
 		let c = Arc::clone(self);
-		let fetch: FetchFn<Single<MonetaryAccountBankWrapper>> = Arc::new(move || {
-			let c = Arc::clone(&c);
-			Box::pin(async move { c.client.get_monetary_account(bank_account_id).await })
-		});
+		let fetch: FetchFn<BunqResponse<Single<MonetaryAccountBankWrapper>>> =
+			Arc::new(move || {
+				let c = Arc::clone(&c);
+				Box::pin(async move { c.client.get_monetary_account(bank_account_id).await })
+			});
 		schedule(
 			&self.ratelimiter_get,
 			fetch,
@@ -234,23 +279,24 @@ impl ClientRateLimited {
 	/// success or `Err(`[`RateLimitExhausted`]`)` if all retries are used up.
 	pub async fn get_payment_request_ratelimited<F, Fut>(
 		self: &Arc<Self>,
-		monetary_account_id: u32,
-		payment_request_id: u32,
+		monetary_account_id: MonetaryAccountId,
+		payment_request_id: BunqMeTabId,
 		on_response: F,
 	) -> Duration
 	where
-		F: Fn(Result<ApiResponse<Single<BunqMeTabWrapper>>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
+		F: Fn(Result<BunqResponse<Single<BunqMeTabWrapper>>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
 		Fut: Future<Output = ()> + Send + 'static,
 	{
 		let c = Arc::clone(self);
-		let fetch: FetchFn<Single<BunqMeTabWrapper>> = Arc::new(move || {
-			let c = Arc::clone(&c);
-			Box::pin(async move {
-				c.client
-					.get_payment_request(monetary_account_id, payment_request_id)
-					.await
-			})
-		});
+		let fetch: FetchFn<BunqResponse<Single<BunqMeTabWrapper>>> =
+			Arc::new(move || {
+				let c = Arc::clone(&c);
+				Box::pin(async move {
+					c.client
+						.get_payment_request(monetary_account_id, payment_request_id)
+						.await
+				})
+			});
 		schedule(
 			&self.ratelimiter_get,
 			fetch,
@@ -260,37 +306,44 @@ impl ClientRateLimited {
 		.await
 	}
 
-	/// Creates a new bunq.me payment request.
-	///
-	/// `amount` is always interpreted as EUR.
+	/// Creates a new bunq.me payment request after checking that `amount` is
+	/// in the account's currency. See [`Client::create_payment_request`].
 	///
-	/// `on_response` is called (on a spawned task) with `Ok(response)` on
-	/// success or `Err(`[`RateLimitExhausted`]`)` if all retries are used up.
-	/// 429 responses are retried automatically, which means `fetch` — and
-	/// therefore the POST — may be called more than once.
+	/// `on_response` is called (on a spawned task) with `Ok(result)` on
+	/// success or `Err(`[`RateLimitExhausted`]`)` if all retries are used up;
+	/// `result` holds the [`ValidationError`] if the input was rejected
+	/// before sending. 429 responses are retried automatically, which means
+	/// `fetch` — and therefore the POST — may be called more than once.
 	pub async fn create_payment_request_ratelimited<F, Fut>(
 		self: &Arc<Self>,
-		monetary_account_id: u32,
-		amount: Decimal,
+		monetary_account_id: MonetaryAccountId,
+		amount: Amount,
 		description: String,
 		redirect_url: String,
 		on_response: F,
 	) -> Duration
 	where
-		F: Fn(Result<ApiResponse<Single<CreateBunqMeTabResponseWrapper>>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
+		F: Fn(Result<Result<BunqResponse<BunqMeTabHandle>, ValidationError>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
 		Fut: Future<Output = ()> + Send + 'static,
 	{
 		let c = Arc::clone(self);
-		let fetch: FetchFn<Single<CreateBunqMeTabResponseWrapper>> = Arc::new(move || {
-			let c = Arc::clone(&c);
-			let description = description.clone();
-			let redirect_url = redirect_url.clone();
-			Box::pin(async move {
-				c.client
-					.create_payment_request(monetary_account_id, amount, description, redirect_url)
-					.await
-			})
-		});
+		let fetch: FetchFn<Result<BunqResponse<BunqMeTabHandle>, ValidationError>> =
+			Arc::new(move || {
+				let c = Arc::clone(&c);
+				let amount = amount.clone();
+				let description = description.clone();
+				let redirect_url = redirect_url.clone();
+				Box::pin(async move {
+					c.client
+						.create_payment_request(
+							monetary_account_id,
+							amount,
+							description,
+							redirect_url,
+						)
+						.await
+				})
+			});
 		schedule(
 			&self.ratelimiter_post,
 			fetch,
@@ -306,23 +359,57 @@ impl ClientRateLimited {
 	/// success or `Err(`[`RateLimitExhausted`]`)` if all retries are used up.
 	pub async fn close_payment_request_ratelimited<F, Fut>(
 		self: &Arc<Self>,
-		monetary_account_id: u32,
-		payment_request_id: u32,
+		monetary_account_id: MonetaryAccountId,
+		payment_request_id: BunqMeTabId,
 		on_response: F,
 	) -> Duration
 	where
-		F: Fn(Result<ApiResponse<Single<CreateBunqMeTabResponseWrapper>>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
+		F: Fn(Result<BunqResponse<Single<AlterBunqMeTabResponseWrapper>>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
 		Fut: Future<Output = ()> + Send + 'static,
 	{
 		let c = Arc::clone(self);
-		let fetch: FetchFn<Single<CreateBunqMeTabResponseWrapper>> = Arc::new(move || {
-			let c = Arc::clone(&c);
-			Box::pin(async move {
-				c.client
-					.close_payment_request(monetary_account_id, payment_request_id)
-					.await
-			})
-		});
+		let fetch: FetchFn<BunqResponse<Single<AlterBunqMeTabResponseWrapper>>> =
+			Arc::new(move || {
+				let c = Arc::clone(&c);
+				Box::pin(async move {
+					c.client
+						.close_payment_request(monetary_account_id, payment_request_id)
+						.await
+				})
+			});
+		schedule(
+			&self.ratelimiter_put,
+			fetch,
+			Arc::new(move |r| Box::pin(on_response(r))),
+			self.max_retries,
+		)
+		.await
+	}
+
+	/// Reopens a cancelled bunq.me payment request.
+	///
+	/// `on_response` is called (on a spawned task) with `Ok(response)` on
+	/// success or `Err(`[`RateLimitExhausted`]`)` if all retries are used up.
+	pub async fn reopen_payment_request_ratelimited<F, Fut>(
+		self: &Arc<Self>,
+		monetary_account_id: MonetaryAccountId,
+		payment_request_id: BunqMeTabId,
+		on_response: F,
+	) -> Duration
+	where
+		F: Fn(Result<BunqResponse<Single<AlterBunqMeTabResponseWrapper>>, RateLimitExhausted>) -> Fut + Send + Sync + 'static,
+		Fut: Future<Output = ()> + Send + 'static,
+	{
+		let c = Arc::clone(self);
+		let fetch: FetchFn<BunqResponse<Single<AlterBunqMeTabResponseWrapper>>> =
+			Arc::new(move || {
+				let c = Arc::clone(&c);
+				Box::pin(async move {
+					c.client
+						.reopen_payment_request(monetary_account_id, payment_request_id)
+						.await
+				})
+			});
 		schedule(
 			&self.ratelimiter_put,
 			fetch,
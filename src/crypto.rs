@@ -0,0 +1,211 @@
+//! The RSA keys requests are signed and responses are verified with.
+//!
+//! With the default `openssl` feature the keys are openssl `PKey`s, so they
+//! can be shared with other code built on openssl. Without it, and always on
+//! `wasm32` where openssl does not build, the pure-Rust
+//! [`rsa`](https://docs.rs/rsa) crate is used instead and the keys are its
+//! `RsaPrivateKey` and `RsaPublicKey`. The rest of the API is the same on
+//! both. To leave out openssl on native targets, turn off the default
+//! features and enable `rsa`, together with `rustls-tls` for the connection
+//! to Bunq:
+//!
+//! ```toml
+//! bunqers = { version = "0.1", default-features = false, features = ["rsa", "rustls-tls"] }
+//! ```
+//!
+//! Bunq signs with PKCS#1 v1.5 padding over a SHA-256 digest, which both
+//! backends implement.
+
+pub(crate) use backend::*;
+pub use backend::{KeyError, PrivateKey, PublicKey};
+
+#[cfg(all(
+	not(target_arch = "wasm32"),
+	not(feature = "openssl"),
+	not(feature = "rsa")
+))]
+compile_error!("enable either the `openssl` or the `rsa` feature to sign requests");
+
+#[cfg(all(feature = "openssl", not(target_arch = "wasm32")))]
+mod backend {
+	use openssl::{
+		error::ErrorStack,
+		hash::MessageDigest,
+		pkey::{PKey, Private, Public},
+		rsa::Rsa,
+		sign::{Signer, Verifier},
+	};
+
+	/// The client's private key.
+	pub type PrivateKey = PKey<Private>;
+	/// Bunq's public key.
+	pub type PublicKey = PKey<Public>;
+	/// Why a key could not be created, parsed or used.
+	pub type KeyError = ErrorStack;
+
+	/// Generates a new RSA key pair of `bits` bits.
+	pub(crate) fn generate_private_key(bits: u32) -> Result<PrivateKey, KeyError> {
+		PKey::from_rsa(Rsa::generate(bits)?)
+	}
+
+	/// Parses a PEM-encoded private key, either PKCS#8 or PKCS#1.
+	pub(crate) fn private_key_from_pem(pem: &[u8]) -> Result<PrivateKey, KeyError> {
+		PKey::private_key_from_pem(pem)
+	}
+
+	/// Parses a DER-encoded private key, either PKCS#8 or PKCS#1.
+	pub(crate) fn private_key_from_der(der: &[u8]) -> Result<PrivateKey, KeyError> {
+		PKey::private_key_from_der(der)
+	}
+
+	/// Parses a DER-encoded PKCS#8 private key.
+	pub(crate) fn private_key_from_pkcs8(der: &[u8]) -> Result<PrivateKey, KeyError> {
+		PKey::private_key_from_pkcs8(der)
+	}
+
+	/// Encodes `key` as a PKCS#8 PEM (`BEGIN PRIVATE KEY`).
+	pub(crate) fn private_key_to_pem_pkcs8(key: &PrivateKey) -> Result<Vec<u8>, KeyError> {
+		key.private_key_to_pem_pkcs8()
+	}
+
+	/// Returns an error if `key` is not an RSA key.
+	pub(crate) fn check_rsa(key: &PrivateKey) -> Result<(), KeyError> {
+		key.rsa().map(drop)
+	}
+
+	/// Returns the size of `key` in bits.
+	pub(crate) fn key_bits(key: &PrivateKey) -> u32 {
+		key.bits()
+	}
+
+	/// Returns the public half of `key`.
+	pub(crate) fn public_key(key: &PrivateKey) -> Result<PublicKey, KeyError> {
+		PKey::public_key_from_der(&key.public_key_to_der()?)
+	}
+
+	/// Parses a PEM-encoded public key (`BEGIN PUBLIC KEY`).
+	pub(crate) fn public_key_from_pem(pem: &[u8]) -> Result<PublicKey, KeyError> {
+		PKey::public_key_from_pem(pem)
+	}
+
+	/// Encodes `key` as a PEM (`BEGIN PUBLIC KEY`).
+	pub(crate) fn public_key_to_pem(key: &PublicKey) -> Result<Vec<u8>, KeyError> {
+		key.public_key_to_pem()
+	}
+
+	/// Signs `data` with `key`.
+	pub(crate) fn sign(key: &PrivateKey, data: &[u8]) -> Result<Vec<u8>, KeyError> {
+		let mut signer = Signer::new(MessageDigest::sha256(), key)?;
+		signer.update(data)?;
+		signer.sign_to_vec()
+	}
+
+	/// Returns whether `signature` is a valid signature of `data` by `key`.
+	pub(crate) fn verify(key: &PublicKey, data: &[u8], signature: &[u8]) -> Result<bool, KeyError> {
+		let mut verifier = Verifier::new(MessageDigest::sha256(), key)?;
+		verifier.update(data)?;
+		verifier.verify(signature)
+	}
+
+	/// Fills `bytes` with cryptographically secure random bytes.
+	pub(crate) fn random_bytes(bytes: &mut [u8]) -> Result<(), KeyError> {
+		openssl::rand::rand_bytes(bytes)
+	}
+}
+
+#[cfg(not(all(feature = "openssl", not(target_arch = "wasm32"))))]
+mod backend {
+	use rsa::{
+		Pkcs1v15Sign, RsaPrivateKey, RsaPublicKey,
+		pkcs1::DecodeRsaPrivateKey,
+		pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding},
+		rand_core::{OsRng, RngCore},
+		sha2::{Digest, Sha256},
+		traits::PublicKeyParts,
+	};
+
+	/// The client's private key.
+	pub type PrivateKey = RsaPrivateKey;
+	/// Bunq's public key.
+	pub type PublicKey = RsaPublicKey;
+	/// Why a key could not be created, parsed or used.
+	pub type KeyError = rsa::Error;
+
+	/// Generates a new RSA key pair of `bits` bits.
+	pub(crate) fn generate_private_key(bits: u32) -> Result<PrivateKey, KeyError> {
+		RsaPrivateKey::new(&mut OsRng, bits as usize)
+	}
+
+	/// Parses a PEM-encoded private key, either PKCS#8 or PKCS#1.
+	pub(crate) fn private_key_from_pem(pem: &[u8]) -> Result<PrivateKey, KeyError> {
+		let pem = std::str::from_utf8(pem)
+			.map_err(|_| KeyError::Pkcs8(rsa::pkcs8::Error::KeyMalformed))?;
+		RsaPrivateKey::from_pkcs8_pem(pem)
+			.or_else(|error| RsaPrivateKey::from_pkcs1_pem(pem).map_err(|_| KeyError::from(error)))
+	}
+
+	/// Parses a DER-encoded private key, either PKCS#8 or PKCS#1.
+	pub(crate) fn private_key_from_der(der: &[u8]) -> Result<PrivateKey, KeyError> {
+		RsaPrivateKey::from_pkcs8_der(der)
+			.or_else(|error| RsaPrivateKey::from_pkcs1_der(der).map_err(|_| KeyError::from(error)))
+	}
+
+	/// Parses a DER-encoded PKCS#8 private key.
+	pub(crate) fn private_key_from_pkcs8(der: &[u8]) -> Result<PrivateKey, KeyError> {
+		Ok(RsaPrivateKey::from_pkcs8_der(der)?)
+	}
+
+	/// Encodes `key` as a PKCS#8 PEM (`BEGIN PRIVATE KEY`).
+	pub(crate) fn private_key_to_pem_pkcs8(key: &PrivateKey) -> Result<Vec<u8>, KeyError> {
+		Ok(key.to_pkcs8_pem(LineEnding::LF)?.as_bytes().to_vec())
+	}
+
+	/// Returns an error if `key` is not an RSA key, which an `RsaPrivateKey`
+	/// always is.
+	pub(crate) fn check_rsa(_key: &PrivateKey) -> Result<(), KeyError> {
+		Ok(())
+	}
+
+	/// Returns the size of `key` in bits.
+	pub(crate) fn key_bits(key: &PrivateKey) -> u32 {
+		(key.size() * 8) as u32
+	}
+
+	/// Returns the public half of `key`.
+	pub(crate) fn public_key(key: &PrivateKey) -> Result<PublicKey, KeyError> {
+		Ok(key.to_public_key())
+	}
+
+	/// Parses a PEM-encoded public key (`BEGIN PUBLIC KEY`).
+	pub(crate) fn public_key_from_pem(pem: &[u8]) -> Result<PublicKey, KeyError> {
+		let pem = std::str::from_utf8(pem)
+			.map_err(|_| KeyError::Pkcs8(rsa::pkcs8::Error::KeyMalformed))?;
+		RsaPublicKey::from_public_key_pem(pem)
+			.map_err(|error| rsa::pkcs8::Error::from(error).into())
+	}
+
+	/// Encodes `key` as a PEM (`BEGIN PUBLIC KEY`).
+	pub(crate) fn public_key_to_pem(key: &PublicKey) -> Result<Vec<u8>, KeyError> {
+		key.to_public_key_pem(LineEnding::LF)
+			.map(String::into_bytes)
+			.map_err(|error| rsa::pkcs8::Error::from(error).into())
+	}
+
+	/// Signs `data` with `key`.
+	pub(crate) fn sign(key: &PrivateKey, data: &[u8]) -> Result<Vec<u8>, KeyError> {
+		key.sign(Pkcs1v15Sign::new::<Sha256>(), &Sha256::digest(data))
+	}
+
+	/// Returns whether `signature` is a valid signature of `data` by `key`.
+	pub(crate) fn verify(key: &PublicKey, data: &[u8], signature: &[u8]) -> Result<bool, KeyError> {
+		let digest = Sha256::digest(data);
+		Ok(key
+			.verify(Pkcs1v15Sign::new::<Sha256>(), &digest, signature)
+			.is_ok())
+	}
+
+	/// Fills `bytes` with cryptographically secure random bytes.
+	pub(crate) fn random_bytes(bytes: &mut [u8]) -> Result<(), rsa::rand_core::Error> {
+		OsRng.try_fill_bytes(bytes)
+	}
+}
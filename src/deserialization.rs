@@ -11,12 +11,61 @@
 //! so the impls in this module manually walk the JSON value tree using
 //! `serde_json::Value` and `serde_path_to_error` for precise error messages.
 
-use std::any::type_name;
+use std::{cell::Cell, fmt, marker::PhantomData};
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use serde::{
+	Deserialize, Serialize, Serializer,
+	de::{Error, IgnoredAny, MapAccess, Visitor},
+	ser::{SerializeMap, SerializeStruct},
+};
+
+use crate::{
+	crypto::{self, PublicKey},
+	types::*,
+};
+
+thread_local! {
+	/// Whether unknown fields are rejected by [`deserialize_value`]. Set by the
+	/// messenger for the duration of a single (synchronous) parse.
+	static STRICT_PARSING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `parse` with strict parsing enabled or disabled on this thread,
+/// restoring the previous setting afterwards.
+pub(crate) fn with_strict_parsing<R>(strict: bool, parse: impl FnOnce() -> R) -> R {
+	let previous = STRICT_PARSING.with(|cell| cell.replace(strict));
+	let result = parse();
+	STRICT_PARSING.with(|cell| cell.set(previous));
+	result
+}
 
-use chrono::NaiveDateTime;
-use serde::{Deserialize, de::Error};
+/// Deserialises `value` into `T` with path-aware error messages. In strict
+/// mode, any field that `T` does not model is reported as an error.
+fn deserialize_value<'de, T, E>(value: serde_json::Value) -> Result<T, E>
+where
+	T: Deserialize<'de>,
+	E: Error,
+{
+	if !STRICT_PARSING.with(Cell::get) {
+		return serde_path_to_error::deserialize(value).map_err(E::custom);
+	}
 
-use crate::types::*;
+	let mut unknown_fields = Vec::new();
+	let parsed = serde_path_to_error::deserialize(serde_ignored::Deserializer::new(
+		value,
+		&mut |path: serde_ignored::Path| unknown_fields.push(path.to_string()),
+	))
+	.map_err(E::custom)?;
+
+	if !unknown_fields.is_empty() {
+		return Err(E::custom(format!(
+			"Unknown fields in strict mode: {}",
+			unknown_fields.join(", ")
+		)));
+	}
+	Ok(parsed)
+}
 
 /// Deserialises [`ApiResponseBody<T>`] by checking whether the top-level
 /// JSON object contains an `"Error"` key (API error) or a `"Response"` key
@@ -45,14 +94,24 @@ where
 
 		let content: Result<T, _> = serde_path_to_error::deserialize(root);
 		match content {
-			Ok(content) => return Ok(ApiResponseBody::Ok(content)),
-			Err(parse_error) => return Err(D::Error::custom(format!("Response: {parse_error}"))),
+			Ok(content) => Ok(ApiResponseBody::Ok(content)),
+			Err(parse_error) => Err(D::Error::custom(format!("Response: {parse_error}"))),
 		}
 	}
 }
 
 /// Deserialises [`Multiple<T>`] by extracting the `"Response"` array and the
 /// `"Pagination"` object from the envelope.
+///
+/// A missing or malformed envelope is reported as an error:
+///
+/// ```
+/// use bunqers::types::{Multiple, PaymentWrapper};
+///
+/// let no_pagination = r#"{"Response": []}"#;
+/// assert!(serde_json::from_str::<Multiple<PaymentWrapper>>(no_pagination).is_err());
+/// assert!(serde_json::from_str::<Multiple<PaymentWrapper>>("[]").is_err());
+/// ```
 impl<'de, T> Deserialize<'de> for Multiple<T>
 where
 	T: Deserialize<'de>,
@@ -61,7 +120,7 @@ where
 	where
 		D: serde::Deserializer<'de>,
 	{
-		let root = serde_json::Value::deserialize(deserializer).expect("Failed to parse JSON");
+		let root = serde_json::Value::deserialize(deserializer)?;
 
 		let pagination_value = root
 			.get("Pagination")
@@ -77,19 +136,43 @@ where
 			.ok_or_else(|| D::Error::custom("'Response' was not an array"))?;
 		let data: Vec<T> = data_value_array
 			.iter()
-			.map(|value| {
-				T::deserialize(value.clone()).map_err(|e| D::Error::custom(format!("{e}")))
-			})
+			.map(|value| deserialize_value(value.clone()))
 			.collect::<Result<Vec<T>, D::Error>>()?;
 
 		Ok(Self { data, pagination })
 	}
 }
 
+/// Serialises [`Multiple<T>`] into the `{"Response": [...], "Pagination": {...}}`
+/// envelope it was parsed from.
+impl<T> Serialize for Multiple<T>
+where
+	T: Serialize,
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut envelope = serializer.serialize_struct("Multiple", 2)?;
+		envelope.serialize_field("Response", &self.data)?;
+		envelope.serialize_field("Pagination", &self.pagination)?;
+		envelope.end()
+	}
+}
+
 /// Deserialises [`Single<T>`] by extracting the one-element `"Response"` array
 /// from the envelope.
 ///
-/// Returns an error if the `Response` array contains more than one element.
+/// Returns an error if the `Response` array is missing, empty or contains more
+/// than one element:
+///
+/// ```
+/// use bunqers::types::{BunqId, Single};
+///
+/// assert!(serde_json::from_str::<Single<BunqId>>(r#"{"Response": []}"#).is_err());
+/// assert!(serde_json::from_str::<Single<BunqId>>(r#"{"Error": null}"#).is_err());
+/// assert!(serde_json::from_str::<Single<BunqId>>(r#"{"Response": [{"id": 1}]}"#).is_ok());
+/// ```
 impl<'de, T> Deserialize<'de> for Single<T>
 where
 	T: Deserialize<'de>,
@@ -98,29 +181,19 @@ where
 	where
 		D: serde::Deserializer<'de>,
 	{
-		let root = serde_json::Value::deserialize(deserializer).expect("Failed to parse to JSON");
+		let root = serde_json::Value::deserialize(deserializer)?;
 
 		let response_field = root
 			.get("Response")
 			.ok_or_else(|| D::Error::custom("Missing 'Response' field in single-item response"))?
 			.clone();
 
-		let response: Result<Vec<T>, _> = serde_path_to_error::deserialize(response_field);
-
-		let response = match response {
-			Ok(parse_success) => parse_success,
-			Err(parse_error) => {
-				println!(
-					"Parsing of {} failed. Path: {}, error: {}",
-					type_name::<T>(),
-					parse_error.path(),
-					parse_error
-				);
-				return Err(D::Error::custom(format!(
+		let response: Vec<T> =
+			deserialize_value(response_field).map_err(|parse_error: D::Error| {
+				D::Error::custom(format!(
 					"Failed to parse single response item: {parse_error}"
-				)));
-			}
-		};
+				))
+			})?;
 
 		if response.len() > 1 {
 			return Err(D::Error::custom(format!(
@@ -138,62 +211,204 @@ where
 	}
 }
 
+/// Serialises [`Single<T>`] into a one-element `{"Response": [...]}` envelope.
+impl<T> Serialize for Single<T>
+where
+	T: Serialize,
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut envelope = serializer.serialize_struct("Single", 1)?;
+		envelope.serialize_field("Response", std::slice::from_ref(&self.0))?;
+		envelope.end()
+	}
+}
+
+/// Deserialises [`Keyed<T>`] from a JSON object with `T::KEY` as key. Other
+/// keys are ignored.
+impl<'de, T> Deserialize<'de> for Keyed<T>
+where
+	T: BunqObject + Deserialize<'de>,
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		struct KeyedVisitor<T>(PhantomData<T>);
+
+		impl<'de, T> Visitor<'de> for KeyedVisitor<T>
+		where
+			T: BunqObject + Deserialize<'de>,
+		{
+			type Value = Keyed<T>;
+
+			fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+				write!(formatter, "an object with a '{}' key", T::KEY)
+			}
+
+			fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+			where
+				A: MapAccess<'de>,
+			{
+				let mut object = None;
+				while let Some(key) = map.next_key::<String>()? {
+					if key == T::KEY && object.is_none() {
+						object = Some(map.next_value()?);
+					} else {
+						map.next_value::<IgnoredAny>()?;
+					}
+				}
+				object
+					.map(Keyed)
+					.ok_or_else(|| A::Error::missing_field(T::KEY))
+			}
+		}
+
+		deserializer.deserialize_map(KeyedVisitor(PhantomData))
+	}
+}
+
+/// Serialises [`Keyed<T>`] as a JSON object with `T::KEY` as its only key.
+impl<T> Serialize for Keyed<T>
+where
+	T: BunqObject + Serialize,
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		let mut map = serializer.serialize_map(Some(1))?;
+		map.serialize_entry(T::KEY, &self.0)?;
+		map.end()
+	}
+}
+
+/// Deserialises [`Empty`] from a `"Response"` envelope, ignoring whatever the
+/// array contains.
+impl<'de> Deserialize<'de> for Empty {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let root = serde_json::Value::deserialize(deserializer)?;
+
+		root.get("Response")
+			.ok_or_else(|| D::Error::custom("Missing 'Response' field in empty response"))?
+			.as_array()
+			.ok_or_else(|| D::Error::custom("'Response' was not an array"))?;
+
+		Ok(Empty)
+	}
+}
+
+/// Serialises [`Currency`] as its plain ISO 4217 code, e.g. `"EUR"`.
+impl Serialize for Currency {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		serializer.serialize_str(self.code())
+	}
+}
+
+/// Deserialises [`Currency`] from its ISO 4217 code, falling back to
+/// [`Currency::Unknown`] for codes without a dedicated variant.
+impl<'de> Deserialize<'de> for Currency {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let code = String::deserialize(deserializer)?;
+		code.parse()
+			.map_err(|_| D::Error::custom(format!("Invalid currency code '{code}'")))
+	}
+}
+
+/// Deserialises [`AnyMonetaryAccount`] from a `{"MonetaryAccount...": {...}}`
+/// object, whatever the key, reading only the `currency` of the account.
+impl<'de> Deserialize<'de> for AnyMonetaryAccount {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		let root = serde_json::Value::deserialize(deserializer)?;
+
+		let account = root
+			.as_object()
+			.and_then(|object| object.values().next())
+			.ok_or_else(|| D::Error::custom("Monetary account was not a wrapped object"))?;
+		let currency = account
+			.get("currency")
+			.ok_or_else(|| D::Error::custom("No 'currency' in monetary account"))?;
+		let currency = Currency::deserialize(currency).map_err(|e| {
+			D::Error::custom(format!("Failed to parse monetary account currency: {e}"))
+		})?;
+
+		Ok(Self { currency })
+	}
+}
+
 /// Deserialises [`Installation`] by manually walking its heterogeneous
 /// `Response` array: `[{Id}, {Token}, {ServerPublicKey}]`.
+///
+/// Malformed payloads are reported as errors rather than panics:
+///
+/// ```
+/// use bunqers::types::Installation;
+///
+/// let missing_key = r#"{"Response": [{"Id": {"id": 1}}]}"#;
+/// assert!(serde_json::from_str::<Installation>(missing_key).is_err());
+/// assert!(serde_json::from_str::<Installation>(r#"{"Response": {}}"#).is_err());
+/// ```
 impl<'de> Deserialize<'de> for Installation {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
 		D: serde::Deserializer<'de>,
 	{
-		let root = serde_json::Value::deserialize(deserializer).expect("Failed to parse to JSON");
+		let root = serde_json::Value::deserialize(deserializer)?;
 
-		let response_field = root
+		let response_elements = root
 			.get("Response")
-			.expect("No 'Response' field in Installation")
-			.clone();
-		let response_elements = response_field
+			.ok_or_else(|| D::Error::custom("No 'Response' field in Installation"))?
 			.as_array()
-			.expect("'Response' field was not an array");
-
+			.ok_or_else(|| D::Error::custom("'Response' field in Installation was not an array"))?;
 		let mut response_iter = response_elements.iter();
 
-		let id: BunqId = match serde_path_to_error::deserialize(
+		let id: BunqId = deserialize_value(
 			response_iter
 				.next()
-				.expect("Not enough elements in Installation response")
+				.ok_or_else(|| D::Error::custom("Not enough elements in Installation for 'Id'"))?
 				.get("Id")
-				.expect("No 'Id' in Installation response"),
-		) {
-			Ok(id) => id,
-			Err(er) => {
-				println!("Failed to parse: {er}");
-				return Err(D::Error::custom("Failed to parse Installation Id"));
-			}
-		};
+				.ok_or_else(|| D::Error::custom("No 'Id' in Installation response"))?
+				.clone(),
+		)
+		.map_err(|e: D::Error| D::Error::custom(format!("Failed to parse Installation Id: {e}")))?;
 
-		let token_value = response_iter
-			.next()
-			.expect("Not enough elements in installation response")
-			.get("Token")
-			.expect("No 'Token' object in Installation response");
-
-		let token: InstallationToken = match serde_path_to_error::deserialize(token_value) {
-			Ok(token) => token,
-			Err(e) => {
-				println!("Failed to parse: {e}");
-				return Err(D::Error::custom("Failed to parse Installation Token"));
-			}
-		};
+		let token: InstallationToken = deserialize_value(
+			response_iter
+				.next()
+				.ok_or_else(|| D::Error::custom("Not enough elements in Installation for 'Token'"))?
+				.get("Token")
+				.ok_or_else(|| D::Error::custom("No 'Token' object in Installation response"))?
+				.clone(),
+		)
+		.map_err(|e: D::Error| {
+			D::Error::custom(format!("Failed to parse Installation Token: {e}"))
+		})?;
 
 		let bunq_public_key = response_iter
 			.next()
-			.expect("Not enough elements in installation response")
+			.ok_or_else(|| {
+				D::Error::custom("Not enough elements in Installation for 'ServerPublicKey'")
+			})?
 			.get("ServerPublicKey")
-			.expect("No 'ServerPublicKey' in Installation response")
+			.ok_or_else(|| D::Error::custom("No 'ServerPublicKey' in Installation response"))?
 			.get("server_public_key")
-			.expect("No 'server_public_key' inside ServerPublicKey")
+			.ok_or_else(|| D::Error::custom("No 'server_public_key' inside ServerPublicKey"))?
 			.as_str()
-			.expect("'server_public_key' was not a string")
+			.ok_or_else(|| D::Error::custom("'server_public_key' was not a string"))?
 			.to_string();
 
 		Ok(Self {
@@ -223,12 +438,23 @@ impl<'de> Deserialize<'de> for DeviceServerSmall {
 			.ok_or_else(|| D::Error::custom("'id' in DeviceServerSmall was not an integer"))?
 			as u32;
 
-		Ok(DeviceServerSmall { id })
+		Ok(DeviceServerSmall { id: DeviceId(id) })
 	}
 }
 
 /// Deserialises [`Session`] by manually walking its heterogeneous `Response`
 /// array: `[{Id}, {Token}, {UserPerson}]`.
+///
+/// Malformed payloads are reported as errors rather than panics:
+///
+/// ```
+/// use bunqers::types::Session;
+///
+/// let missing_user = r#"{"Response": [{"Id": {"id": 1}}, {"Token": {"token": "t"}}]}"#;
+/// assert!(serde_json::from_str::<Session>(missing_user).is_err());
+/// assert!(serde_json::from_str::<Session>(r#"{"Response": [{"Id": {"id": "1"}}]}"#).is_err());
+/// assert!(serde_json::from_str::<Session>(r#"{"Response": {}}"#).is_err());
+/// ```
 impl<'de> Deserialize<'de> for Session {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
 	where
@@ -255,29 +481,31 @@ impl<'de> Deserialize<'de> for Session {
 			.ok_or_else(|| D::Error::custom("'id' in Session was not an integer"))?
 			as u32;
 
-		let token = serde_path_to_error::deserialize(
+		let token = deserialize_value(
 			response_iter
 				.next()
 				.ok_or_else(|| D::Error::custom("Not enough elements in Session for 'Token'"))?
 				.get("Token")
-				.ok_or_else(|| {
-					D::Error::custom("Second element in Session did not have 'Token'")
-				})?,
+				.ok_or_else(|| D::Error::custom("Second element in Session did not have 'Token'"))?
+				.clone(),
 		)
-		.map_err(|e| D::Error::custom(format!("Failed to parse Token in Session: {e}")))?;
+		.map_err(|e: D::Error| {
+			D::Error::custom(format!("Failed to parse Token in Session: {e}"))
+		})?;
 
-		let user_person = serde_path_to_error::deserialize(
+		let user_person = deserialize_value(
 			response_iter
 				.next()
-				.ok_or_else(|| {
-					D::Error::custom("Not enough elements in Session for 'UserPerson'")
-				})?
+				.ok_or_else(|| D::Error::custom("Not enough elements in Session for 'UserPerson'"))?
 				.get("UserPerson")
 				.ok_or_else(|| {
 					D::Error::custom("Third element in Session did not have 'UserPerson'")
-				})?,
+				})?
+				.clone(),
 		)
-		.map_err(|e| D::Error::custom(format!("Failed to parse UserPerson in Session: {e}")))?;
+		.map_err(|e: D::Error| {
+			D::Error::custom(format!("Failed to parse UserPerson in Session: {e}"))
+		})?;
 
 		Ok(Session {
 			id,
@@ -287,13 +515,159 @@ impl<'de> Deserialize<'de> for Session {
 	}
 }
 
-/// Parses a Bunq date-time string (`"YYYY-MM-DD HH:MM:SS.f"`) into a
-/// [`NaiveDateTime`].
+/// Date-time formats without an offset (or explicitly in UTC) that Bunq has
+/// been seen to send. `%.f` also matches when fractional seconds are absent.
+const NAIVE_DATE_FORMATS: [&str; 4] = [
+	"%Y-%m-%d %H:%M:%S%.f",
+	"%Y-%m-%dT%H:%M:%S%.f",
+	"%Y-%m-%d %H:%M:%S%.fZ",
+	"%Y-%m-%dT%H:%M:%S%.fZ",
+];
+
+/// Date-time formats with a UTC offset. Such values are converted to UTC.
+const OFFSET_DATE_FORMATS: [&str; 3] = [
+	"%Y-%m-%d %H:%M:%S%.f%:z",
+	"%Y-%m-%d %H:%M:%S%.f%z",
+	"%Y-%m-%dT%H:%M:%S%.f%:z",
+];
+
+/// Parses a date-time string in any of the formats Bunq uses, or a plain
+/// `YYYY-MM-DD` date (taken as midnight).
+fn parse_date(s: &str) -> Option<NaiveDateTime> {
+	let s = s.trim();
+	NAIVE_DATE_FORMATS
+		.iter()
+		.find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+		.or_else(|| {
+			OFFSET_DATE_FORMATS.iter().find_map(|format| {
+				DateTime::parse_from_str(s, format)
+					.ok()
+					.map(|date| date.naive_utc())
+			})
+		})
+		.or_else(|| {
+			NaiveDate::parse_from_str(s, "%Y-%m-%d")
+				.ok()
+				.and_then(|date| date.and_hms_opt(0, 0, 0))
+		})
+}
+
+/// Parses a Bunq date-time string (usually `"YYYY-MM-DD HH:MM:SS.ffffff"`)
+/// into a [`NaiveDateTime`].
+///
+/// Fractional seconds are optional, a `T` separator is accepted, and values
+/// with a UTC offset are converted to UTC:
+///
+/// ```
+/// use bunqers::deserialization::deserialize_date;
+/// use chrono::{DateTime, NaiveDate, NaiveDateTime};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Event {
+///     #[serde(deserialize_with = "deserialize_date")]
+///     at: NaiveDateTime,
+/// }
+///
+/// let expected = NaiveDate::from_ymd_opt(2024, 5, 1)
+///     .and_then(|date| date.and_hms_opt(10, 0, 0))
+///     .unwrap();
+/// for at in [
+///     "2024-05-01 10:00:00.000000",
+///     "2024-05-01 10:00:00",
+///     "2024-05-01T12:00:00+02:00",
+///     "2024-05-01 10:00:00.0Z",
+/// ] {
+///     let event: Event = serde_json::from_str(&format!(r#"{{"at": "{at}"}}"#)).unwrap();
+///     assert_eq!(event.at, expected);
+/// }
+/// ```
 pub fn deserialize_date<'de, D>(deserializer: D) -> Result<NaiveDateTime, D::Error>
 where
 	D: serde::Deserializer<'de>,
 {
 	let s = String::deserialize(deserializer)?;
-	NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S%.f")
-		.map_err(|e| D::Error::custom(format!("Invalid date-time '{}': {}", s, e)))
+	parse_date(&s).ok_or_else(|| D::Error::custom(format!("Invalid date-time '{s}'")))
+}
+
+/// Parses an optional Bunq date-time string, treating `null` and the empty
+/// string as `None`.
+pub fn deserialize_optional_date<'de, D>(deserializer: D) -> Result<Option<NaiveDateTime>, D::Error>
+where
+	D: serde::Deserializer<'de>,
+{
+	let s = Option::<String>::deserialize(deserializer)?;
+	match s {
+		Some(s) if !s.trim().is_empty() => parse_date(&s)
+			.map(Some)
+			.ok_or_else(|| D::Error::custom(format!("Invalid date-time '{s}'"))),
+		_ => Ok(None),
+	}
+}
+
+/// Formats a [`NaiveDateTime`] in the Bunq date-time format
+/// (`"YYYY-MM-DD HH:MM:SS.ffffff"`) for request bodies.
+pub fn serialize_date<S>(date: &NaiveDateTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	serializer.serialize_str(&date.format("%Y-%m-%d %H:%M:%S%.6f").to_string())
+}
+
+/// Formats an optional [`NaiveDateTime`] in the Bunq date-time format
+/// (`"YYYY-MM-DD HH:MM:SS.ffffff"`) for request bodies.
+pub fn serialize_optional_date<S>(
+	date: &Option<NaiveDateTime>,
+	serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	match date {
+		Some(date) => serializer.serialize_str(&date.format("%Y-%m-%d %H:%M:%S%.6f").to_string()),
+		None => serializer.serialize_none(),
+	}
+}
+
+/// Parses an optional Bunq pagination URL into a [`PageCursor`].
+pub fn deserialize_page_cursor<'de, D>(deserializer: D) -> Result<Option<PageCursor>, D::Error>
+where
+	D: serde::Deserializer<'de>,
+{
+	let url = Option::<String>::deserialize(deserializer)?;
+	Ok(url.map(|url| PageCursor::from_url(&url)))
+}
+
+/// Formats an optional [`PageCursor`] back into a Bunq pagination URL.
+pub fn serialize_page_cursor<S>(
+	cursor: &Option<PageCursor>,
+	serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	match cursor {
+		Some(cursor) => serializer.serialize_str(&cursor.to_url()),
+		None => serializer.serialize_none(),
+	}
+}
+
+/// Formats an RSA public key as a PEM string, so that builder states and
+/// [`SessionContext`](crate::client::SessionContext) can be persisted.
+pub fn serialize_public_key<S>(key: &PublicKey, serializer: S) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	let pem = crypto::public_key_to_pem(key).map_err(serde::ser::Error::custom)?;
+	let pem = String::from_utf8(pem).map_err(serde::ser::Error::custom)?;
+	serializer.serialize_str(&pem)
+}
+
+/// Parses an RSA public key from a PEM string.
+pub fn deserialize_public_key<'de, D>(deserializer: D) -> Result<PublicKey, D::Error>
+where
+	D: serde::Deserializer<'de>,
+{
+	let pem = String::deserialize(deserializer)?;
+	crypto::public_key_from_pem(pem.as_bytes()).map_err(D::Error::custom)
 }
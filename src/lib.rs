@@ -14,15 +14,16 @@
 //! use bunqers::InstallationContext;
 //!
 //! # #[tokio::main]
-//! # async fn main() {
+//! # async fn main() -> Result<(), bunqers::client_builder::FlowError> {
 //! let installation: InstallationContext = bunqers::install_device(
-//!     "your-api-key".into(),
-//!     "https://api.bunq.com/v1".into(),
-//!     "my-app".into(),
-//!     "my-device".into(),
-//! ).await;
+//!     "your-api-key",
+//!     bunqers::PRODUCTION_API_URL,
+//!     "my-app",
+//!     "my-device",
+//! ).await?;
 //!
 //! // Serialise and save `installation` to disk (e.g. as JSON).
+//! # Ok(())
 //! # }
 //! ```
 //!
@@ -30,13 +31,14 @@
 //!
 //! ```rust,no_run
 //! # #[tokio::main]
-//! # async fn main() {
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! # let installation: bunqers::InstallationContext = todo!();
 //! // Load `installation` from disk, then:
-//! let client = bunqers::create_client(installation, None).await;
+//! let client = bunqers::create_client(installation, None).await?;
 //!
-//! let user = client.get_user().await.into_result().unwrap();
+//! let user = client.get_user().await.into_result()?;
 //! println!("Hello, {}!", user.user_person.display_name);
+//! # Ok(())
 //! # }
 //! ```
 //!
@@ -54,15 +56,16 @@
 //! use std::sync::Arc;
 //!
 //! # #[tokio::main]
-//! # async fn main() {
+//! # async fn main() -> Result<(), bunqers::client_builder::FlowError> {
 //! # let installation: bunqers::InstallationContext = todo!();
-//! let client_rl = Arc::new(bunqers::create_rate_limited_client(installation, None).await);
+//! let client_rl = Arc::new(bunqers::create_rate_limited_client(installation, None, 3).await?);
 //!
 //! client_rl.get_user_ratelimited(|result| async move {
 //!     let response = result.expect("rate limit exhausted");
 //!     let user = response.into_result().expect("API error");
 //!     println!("Hello, {}!", user.user_person.display_name);
 //! }).await;
+//! # Ok(())
 //! # }
 //! ```
 //!
@@ -70,33 +73,75 @@
 //!
 //! | Feature | Description |
 //! |---------|-------------|
-//! | `ratelimited` | Enables [`create_rate_limited_client`] and [`client_rate_limited::ClientRateLimited`], which queue requests through [`ritlers`](https://crates.io/crates/ritlers) and auto-retry on 429 responses |
+//! | `blocking` | Enables [`blocking::Client`], which offers the same endpoints without requiring an async runtime |
+//! | `default-tls` | On by default. Connects to Bunq over reqwest's default TLS backend, which is openssl on Linux |
+//! | `keepalive` | Enables [`Client::keep_alive`](client::Client::keep_alive), which periodically uses the session so it does not time out between bursts of traffic |
+//! | `openssl` | On by default. Signs requests with openssl; without it the pure-Rust `rsa` crate is used, see [`crypto`] |
+//! | `polling` | Enables [`Client::wait_for_payment`](client::Client::wait_for_payment), which polls a bunq.me payment request until it is paid, expired or cancelled |
+//! | `ratelimited` | Enables [`create_rate_limited_client`], [`client_rate_limited::ClientRateLimited`] and [`session_manager::SessionManager`], which queue requests through [`ritlers`](https://crates.io/crates/ritlers) and auto-retry on 429 responses |
+//! | `rsa` | Allows converting an [`rsa::RsaPrivateKey`](https://docs.rs/rsa) into a [`signing_key::SigningKey`], and signs requests with it when `openssl` is turned off |
+//! | `rustls-tls` | Connects to Bunq over rustls instead, e.g. together with `rsa` and without the default features to not link openssl at all |
+
+use std::fmt;
 
-use openssl::pkey::PKey;
 use serde::{Deserialize, Serialize};
 
 use crate::{
 	client::Client,
-	client_builder::{ClientBuilder, Registered, UncheckedSession},
+	client_builder::{
+		BuildErrorReason, ClientBuilder, FlowError, FlowStage, Registered, Revealed, Secret,
+		UncheckedSession,
+	},
+	types::DeviceId,
 };
 
-#[cfg(feature = "ratelimited")]
-use std::time::Duration;
-
-#[cfg(feature = "ratelimited")]
-use ritlers::async_rt::RateLimiter;
-
 #[cfg(feature = "ratelimited")]
 use crate::client_rate_limited::ClientRateLimited;
 
+// These features run on tokio's timer or blocking runtime, neither of which is
+// available in a browser.
+#[cfg(all(
+	target_arch = "wasm32",
+	any(
+		feature = "blocking",
+		feature = "keepalive",
+		feature = "polling",
+		feature = "ratelimited"
+	)
+))]
+compile_error!(
+	"the `blocking`, `keepalive`, `polling` and `ratelimited` features are not supported on wasm32"
+);
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
 pub mod client_builder;
+pub mod crypto;
 pub mod deserialization;
 pub mod messenger;
+pub mod prelude;
+pub mod rate_limiter;
+pub mod resources;
+pub mod session_state;
+pub mod signing_key;
 pub mod types;
+pub mod validation;
+
+mod sleep;
 
 #[cfg(feature = "ratelimited")]
 pub mod client_rate_limited;
+#[cfg(feature = "ratelimited")]
+pub mod session_manager;
+
+pub use client_builder::{PRODUCTION_API_URL, SANDBOX_API_URL};
+
+/// The `openssl` version used for the keys in this crate's public API.
+#[cfg(all(feature = "openssl", not(target_arch = "wasm32")))]
+pub use openssl;
+/// The `rust_decimal` version used for amounts in this crate's public API.
+pub use rust_decimal;
 
 /// All credentials needed to authenticate with the Bunq API.
 ///
@@ -104,7 +149,10 @@ pub mod client_rate_limited;
 /// an RSA key pair (see [`install_device`]). Serialise it to disk so that
 /// subsequent runs can skip device registration and go straight to
 /// [`create_client`].
-#[derive(Serialize, Deserialize, Debug, Clone)]
+///
+/// The `Debug` output masks the tokens, the API key and the private key; use
+/// [`reveal`](InstallationContext::reveal) to include them.
+#[derive(Serialize, Deserialize, Clone)]
 pub struct InstallationContext {
 	/// Short-lived token issued by the `/installation` endpoint.
 	/// Used as `X-Bunq-Client-Authentication` during device registration.
@@ -112,7 +160,7 @@ pub struct InstallationContext {
 	/// Bunq's RSA public key in PEM format, used to verify response signatures.
 	pub bunq_public_key: String,
 	/// The numeric device ID assigned by the `/device-server` endpoint.
-	pub registered_device_id: u32,
+	pub registered_device_id: DeviceId,
 	/// The Bunq API key used to register the device.
 	pub bunq_api_key: String,
 	/// The client's RSA private key in PKCS#8 PEM format, used to sign requests.
@@ -121,10 +169,48 @@ pub struct InstallationContext {
 	pub client_public_key: String,
 	/// Base URL of the Bunq API (e.g. `https://api.bunq.com/v1`).
 	pub api_base_url: String,
-	/// Application name sent as the `User-Agent` header.
+	/// Application name, passed to the [`ClientBuilder`].
 	pub app_name: String,
 }
 
+impl InstallationContext {
+	/// Returns a value whose `Debug` output includes the secrets.
+	pub fn reveal(&self) -> Revealed<'_, Self> {
+		Revealed(self)
+	}
+
+	fn fmt_debug(&self, f: &mut fmt::Formatter<'_>, reveal: bool) -> fmt::Result {
+		f.debug_struct("InstallationContext")
+			.field(
+				"installation_token",
+				&Secret(&self.installation_token, reveal),
+			)
+			.field("bunq_public_key", &self.bunq_public_key)
+			.field("registered_device_id", &self.registered_device_id)
+			.field("bunq_api_key", &Secret(&self.bunq_api_key, reveal))
+			.field(
+				"client_private_key",
+				&Secret(&self.client_private_key, reveal),
+			)
+			.field("client_public_key", &self.client_public_key)
+			.field("api_base_url", &self.api_base_url)
+			.field("app_name", &self.app_name)
+			.finish()
+	}
+}
+
+impl fmt::Debug for InstallationContext {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.fmt_debug(f, false)
+	}
+}
+
+impl fmt::Debug for Revealed<'_, InstallationContext> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt_debug(f, true)
+	}
+}
+
 /// Registers the current device with the Bunq API.
 ///
 /// This performs the full three-step registration flow:
@@ -137,50 +223,49 @@ pub struct InstallationContext {
 /// no need to call this function again unless the device registration is
 /// revoked.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Panics if any step of the registration flow fails (key generation, network
-/// error, or an API error response from Bunq).
+/// Returns a [`FlowError`] telling which step failed (key generation,
+/// network error, or an API error response from Bunq).
 pub async fn install_device(
-	bunq_api_key: String,
-	api_base_url: String,
-	app_name: String,
-	device_description: String,
-) -> InstallationContext {
+	bunq_api_key: impl Into<String>,
+	api_base_url: impl Into<String>,
+	app_name: impl Into<String>,
+	device_description: impl Into<String>,
+) -> Result<InstallationContext, FlowError> {
+	let api_base_url: String = api_base_url.into();
+	let app_name: String = app_name.into();
+
 	println!("Installing device...");
 	let builder = ClientBuilder::new_without_key(api_base_url.clone(), app_name.clone())
-		.expect("Failed to create public and private key pair")
+		.map_err(FlowError::at(FlowStage::Install))?
 		.install_device()
 		.await
-		.expect("Failed to install device")
-		.register_device(bunq_api_key, &device_description)
+		.map_err(FlowError::at(FlowStage::Install))?
+		.register_device(bunq_api_key, device_description)
 		.await
-		.expect("Failed to register device");
+		.map_err(FlowError::at(FlowStage::Register))?;
 
+	let serialization_error = |error| FlowError {
+		stage: FlowStage::Register,
+		source: BuildErrorReason::KeySerialization(error),
+	};
 	let bunq_public_key = String::from_utf8(
-		builder
-			.context
-			.bunq_public_key
-			.public_key_to_pem()
-			.expect("Failed to serialize Bunq's public key"),
+		crypto::public_key_to_pem(&builder.context.bunq_public_key).map_err(serialization_error)?,
 	)
 	.expect("Bunq's public key contained non-UTF-8 characters");
-	let client_private_key = String::from_utf8(
-		builder
-			.private_key
-			.private_key_to_pem_pkcs8()
-			.expect("Failed to serialize client's private key"),
-	)
-	.expect("Client's private key contained non-UTF-8 characters");
+	let client_private_key = builder.export_key_pem().map_err(|source| FlowError {
+		stage: FlowStage::Register,
+		source,
+	})?;
 	let client_public_key = String::from_utf8(
-		builder
-			.private_key
-			.public_key_to_pem()
-			.expect("Failed to serialize client's public key"),
+		crypto::public_key(&builder.private_key)
+			.and_then(|key| crypto::public_key_to_pem(&key))
+			.map_err(serialization_error)?,
 	)
 	.expect("Client's public key contained non-UTF-8 characters");
 
-	InstallationContext {
+	Ok(InstallationContext {
 		installation_token: builder.context.installation_token,
 		bunq_public_key,
 		registered_device_id: builder.context.registered_device_id,
@@ -189,7 +274,7 @@ pub async fn install_device(
 		client_public_key,
 		api_base_url,
 		app_name,
-	}
+	})
 }
 
 /// Creates a [`Client`] from a previously obtained [`InstallationContext`].
@@ -201,21 +286,29 @@ pub async fn install_device(
 ///
 /// If `session_token` is `None`, a fresh session is always created.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Panics if session creation fails (e.g. if the device registration has been
-/// revoked).
+/// Returns a [`FlowError`] if the stored keys cannot be parsed or session
+/// creation fails (e.g. if the device registration has been revoked).
 pub async fn create_client(
 	installation_context: InstallationContext,
 	session_token: Option<String>,
-) -> Client {
-	let bunq_public_key =
-		PKey::public_key_from_pem(installation_context.bunq_public_key.as_bytes())
-			.expect("Failed to parse Bunq's public key");
+) -> Result<Client, FlowError> {
+	let bunq_public_key = crypto::public_key_from_pem(
+		installation_context.bunq_public_key.as_bytes(),
+	)
+	.map_err(|error| FlowError {
+		stage: FlowStage::Session,
+		source: BuildErrorReason::KeyDeserializationError(error),
+	})?;
 
-	let client_private_key =
-		PKey::private_key_from_pem(installation_context.client_private_key.as_bytes())
-			.expect("Failed to parse Client's private key");
+	let client_private_key = crypto::private_key_from_pem(
+		installation_context.client_private_key.as_bytes(),
+	)
+	.map_err(|error| FlowError {
+		stage: FlowStage::Session,
+		source: BuildErrorReason::InvalidPrivateKey(error),
+	})?;
 
 	if let Some(session_token) = session_token {
 		// Attempt to reuse the provided session token.
@@ -236,7 +329,7 @@ pub async fn create_client(
 		.await;
 
 		if let Ok(checked_session) = checked_session {
-			return checked_session.build();
+			return Ok(checked_session.build());
 		} else {
 			println!("Provided session was invalid.");
 		}
@@ -258,8 +351,8 @@ pub async fn create_client(
 	)
 	.create_session()
 	.await
-	.expect("Failed to create session. Is the installation invalidated?")
-	.build()
+	.map(ClientBuilder::build)
+	.map_err(FlowError::at(FlowStage::Session))
 }
 
 /// Creates a [`ClientRateLimited`] from a previously obtained [`InstallationContext`].
@@ -280,10 +373,14 @@ pub async fn create_client(
 /// valid. If it has expired, or if `session_token` is `None`, a new session is
 /// created.
 ///
+/// # Errors
+///
+/// Returns a [`FlowError`] under the same conditions as [`create_client`].
+///
 /// # Panics
 ///
-/// Panics if session creation fails or if the rate limiters cannot be
-/// initialised (neither should happen under normal conditions).
+/// Panics if the rate limiters cannot be initialised (which should not happen
+/// under normal conditions).
 ///
 /// # Example
 ///
@@ -291,15 +388,16 @@ pub async fn create_client(
 /// use std::sync::Arc;
 ///
 /// # #[tokio::main]
-/// # async fn main() {
+/// # async fn main() -> Result<(), bunqers::client_builder::FlowError> {
 /// # let installation: bunqers::InstallationContext = todo!();
-/// let client_rl = Arc::new(bunqers::create_rate_limited_client(installation, None).await);
+/// let client_rl = Arc::new(bunqers::create_rate_limited_client(installation, None, 3).await?);
 ///
 /// client_rl.get_user_ratelimited(|result| async move {
 ///     let response = result.expect("rate limit exhausted");
 ///     let user = response.into_result().expect("API error");
 ///     println!("Hello, {}!", user.user_person.display_name);
 /// }).await;
+/// # Ok(())
 /// # }
 /// ```
 #[cfg(feature = "ratelimited")]
@@ -307,16 +405,7 @@ pub async fn create_rate_limited_client(
 	installation_context: InstallationContext,
 	session_token: Option<String>,
 	max_retries: u32,
-) -> ClientRateLimited {
-	let client = create_client(installation_context, session_token).await;
-	ClientRateLimited {
-		client,
-		ratelimiter_get: RateLimiter::new(3, Duration::from_secs(3))
-			.expect("Failed to create GET rate limiter"),
-		ratelimiter_post: RateLimiter::new(5, Duration::from_secs(3))
-			.expect("Failed to create POST rate limiter"),
-		ratelimiter_put: RateLimiter::new(2, Duration::from_secs(3))
-			.expect("Failed to create PUT rate limiter"),
-		max_retries,
-	}
+) -> Result<ClientRateLimited, FlowError> {
+	let client = create_client(installation_context, session_token).await?;
+	Ok(ClientRateLimited::new(client, max_retries))
 }
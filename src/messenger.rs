@@ -11,18 +11,36 @@
 //!   `X-Bunq-Client-Authentication`.
 //! - Verifying the `X-Bunq-Server-Signature` header on every response.
 
-use std::{fs::File, io::Write};
+use std::{
+	error::Error,
+	fmt,
+	future::Future,
+	sync::{Arc, PoisonError, RwLock},
+	time::Duration,
+};
 
 use base64::{Engine, engine::general_purpose};
-use openssl::{
-	hash::MessageDigest,
-	pkey::{PKey, Private, Public},
-	sign::{Signer, Verifier},
+use bytes::Bytes;
+use chrono::{DateTime, TimeDelta, Utc};
+use reqwest::{
+	Method, StatusCode,
+	header::{HeaderMap, HeaderName, HeaderValue},
 };
-use reqwest::{Method, StatusCode};
 use serde::de::DeserializeOwned;
+use zeroize::Zeroize;
 
-use crate::types::{ApiErrorDescription, ApiResponseBody};
+use crate::{
+	client_builder::{Revealed, Secret},
+	crypto::{self, PrivateKey, PublicKey},
+	deserialization::with_strict_parsing,
+	rate_limiter::RateLimiter,
+	sleep::{Instant, Sleep},
+	types::{
+		ApiErrorDescription, ApiResponseBody, CreateSession, Empty, Multiple, Pagination,
+		Session as BunqSession,
+	},
+	validation::{self, ValidationError},
+};
 
 /// An API-level error returned by Bunq (non-2xx status with an `Error` body).
 #[derive(Debug)]
@@ -31,25 +49,251 @@ pub struct ApiErrorResponse {
 	pub status_code: StatusCode,
 	/// Human-readable error descriptions from the response body.
 	pub reasons: Vec<ApiErrorDescription>,
+	/// How often the request was sent again after being rate limited or
+	/// failing transiently, see [`Messenger::set_rate_limit_retries`] and
+	/// [`Messenger::set_retry_policy`].
+	pub retries: u32,
+	/// The wait Bunq asked for in the `Retry-After` header of the response.
+	pub retry_after: Option<Duration>,
+	/// The `X-Bunq-Client-Request-Id` the request was sent with.
+	pub request_id: Option<String>,
+	/// The `X-Bunq-Client-Response-Id` of the response, which Bunq support
+	/// asks for when investigating an error.
+	pub response_id: Option<String>,
+}
+
+impl ApiErrorResponse {
+	/// Classifies the error from its status code and (untranslated)
+	/// descriptions, so callers don't need to match on error text.
+	///
+	/// ```
+	/// use bunqers::{messenger::{ApiErrorResponse, BunqErrorKind}, types::ApiErrorDescription};
+	/// use reqwest::StatusCode;
+	///
+	/// let error = ApiErrorResponse {
+	///     status_code: StatusCode::BAD_REQUEST,
+	///     reasons: vec![ApiErrorDescription {
+	///         description: "Incorrect API key or IP address.".to_string(),
+	///         translated: "Onjuiste API-sleutel of IP-adres.".to_string(),
+	///     }],
+	///     retries: 0,
+	///     retry_after: None,
+	///     request_id: None,
+	///     response_id: None,
+	/// };
+	/// assert_eq!(error.kind(), BunqErrorKind::IncorrectApiKey);
+	///
+	/// let error = ApiErrorResponse {
+	///     status_code: StatusCode::FORBIDDEN,
+	///     reasons: vec![ApiErrorDescription {
+	///         description: "Your IP address is not permitted for this device.".to_string(),
+	///         translated: "Je IP-adres is niet toegestaan voor dit apparaat.".to_string(),
+	///     }],
+	///     retries: 0,
+	///     retry_after: None,
+	///     request_id: None,
+	///     response_id: None,
+	/// };
+	/// assert_eq!(error.kind(), BunqErrorKind::IpBlocked);
+	/// ```
+	pub fn kind(&self) -> BunqErrorKind {
+		let descriptions: Vec<String> = self
+			.reasons
+			.iter()
+			.map(|reason| reason.description.to_lowercase())
+			.collect();
+		let mentions = |text: &str| descriptions.iter().any(|d| d.contains(text));
+
+		if self.status_code == StatusCode::TOO_MANY_REQUESTS || mentions("too many requests") {
+			BunqErrorKind::RateLimited
+		} else if (mentions("ip address") || mentions("ip range"))
+			&& (mentions("not permitted") || mentions("not allowed") || mentions("not whitelisted"))
+		{
+			BunqErrorKind::IpBlocked
+		} else if mentions("incorrect api key") {
+			BunqErrorKind::IncorrectApiKey
+		} else if (mentions("session") || mentions("token")) && mentions("expired") {
+			BunqErrorKind::SessionExpired
+		} else if mentions("insufficient authentication") {
+			BunqErrorKind::InsufficientAuthentication
+		} else if mentions("not allowed") || self.status_code == StatusCode::FORBIDDEN {
+			BunqErrorKind::UserNotAllowed
+		} else if self.status_code == StatusCode::UNAUTHORIZED {
+			BunqErrorKind::InsufficientAuthentication
+		} else if self.status_code == StatusCode::NOT_FOUND {
+			BunqErrorKind::NotFound
+		} else if self.status_code == StatusCode::METHOD_NOT_ALLOWED {
+			BunqErrorKind::MethodNotAllowed
+		} else if self.status_code.is_server_error() {
+			BunqErrorKind::ServerError
+		} else if self.status_code == StatusCode::BAD_REQUEST {
+			BunqErrorKind::BadRequest
+		} else {
+			BunqErrorKind::Other
+		}
+	}
+}
+
+/// The kind of an [`ApiErrorResponse`], see [`ApiErrorResponse::kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BunqErrorKind {
+	/// The request lacked a valid session or installation token, or its
+	/// signature was rejected.
+	InsufficientAuthentication,
+	/// The session token has expired; create a new session.
+	SessionExpired,
+	/// Too many requests were sent in a short time (HTTP 429).
+	RateLimited,
+	/// The API key is wrong, or the request came from an IP address that is
+	/// not permitted for it. Bunq uses one message for both cases.
+	IncorrectApiKey,
+	/// The request came from an IP address outside the permitted IPs of the
+	/// device. Register the device again from the current address to recover.
+	IpBlocked,
+	/// The user may not perform this action (HTTP 403).
+	UserNotAllowed,
+	/// The requested object does not exist (HTTP 404).
+	NotFound,
+	/// The endpoint does not support this HTTP method (HTTP 405).
+	MethodNotAllowed,
+	/// The request was rejected as invalid, e.g. a missing field (HTTP 400).
+	BadRequest,
+	/// Bunq failed to handle the request (HTTP 5xx).
+	ServerError,
+	/// Any error not covered by the other kinds.
+	Other,
 }
 
+impl fmt::Display for ApiErrorResponse {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let descriptions: Vec<&str> = self
+			.reasons
+			.iter()
+			.map(|reason| reason.description.as_str())
+			.collect();
+		write!(
+			f,
+			"Bunq returned {}: {}",
+			self.status_code,
+			descriptions.join("; ")
+		)?;
+		if let Some(response_id) = &self.response_id {
+			write!(f, " (response ID {response_id})")?;
+		}
+		Ok(())
+	}
+}
+
+impl Error for ApiErrorResponse {}
+
 /// A parsed HTTP response from the Bunq API.
 ///
-/// Call [`into_result`](ApiResponse::into_result) to unwrap the successful
-/// body or surface the error. Use [`is_rate_limited`](ApiResponse::is_rate_limited)
+/// Every endpoint returns its result in this envelope, together with the HTTP
+/// status, the `X-Bunq-Client-Response-Id` header and, for list endpoints,
+/// the [`pagination`](BunqResponse::pagination) cursors.
+///
+/// Call [`into_result`](BunqResponse::into_result) to unwrap the successful
+/// body or surface the error. Use [`is_rate_limited`](BunqResponse::is_rate_limited)
 /// to check for 429 responses before consuming the value.
 #[derive(Debug)]
-pub struct ApiResponse<T> {
+pub struct BunqResponse<T> {
 	body: ApiResponseBody<T>,
 	status_code: StatusCode,
+	response_id: Option<String>,
+	raw_body: Option<Bytes>,
+	retries: u32,
+	retry_after: Option<Duration>,
+	request_id: String,
 }
 
-impl<T> ApiResponse<T> {
+/// Former name of [`BunqResponse`].
+#[deprecated(note = "renamed to `BunqResponse`")]
+pub type ApiResponse<T> = BunqResponse<T>;
+
+impl<T> BunqResponse<T> {
 	/// Returns `true` if Bunq responded with HTTP 429 Too Many Requests.
 	pub fn is_rate_limited(&self) -> bool {
 		self.status_code == StatusCode::TOO_MANY_REQUESTS
 	}
 
+	/// Returns the HTTP status code of the response.
+	pub fn status_code(&self) -> StatusCode {
+		self.status_code
+	}
+
+	/// Returns the `X-Bunq-Client-Response-Id` header, which Bunq support
+	/// asks for when investigating a request.
+	pub fn response_id(&self) -> Option<&str> {
+		self.response_id.as_deref()
+	}
+
+	/// Returns the `X-Bunq-Client-Request-Id` the request was sent with, to
+	/// reference the request in support tickets.
+	pub fn request_id(&self) -> &str {
+		&self.request_id
+	}
+
+	/// Returns how often the request was sent again after being rate limited
+	/// or failing transiently before this response was received.
+	pub fn retries(&self) -> u32 {
+		self.retries
+	}
+
+	/// Returns the wait Bunq asked for in the `Retry-After` header, if any.
+	pub fn retry_after(&self) -> Option<Duration> {
+		self.retry_after
+	}
+
+	/// Returns the parsed body if the request succeeded.
+	pub fn value(&self) -> Option<&T> {
+		match &self.body {
+			ApiResponseBody::Ok(body) => Some(body),
+			ApiResponseBody::Err(_) => None,
+		}
+	}
+
+	/// Converts a successful body with `f`, keeping errors, the status code
+	/// and the raw body as they are.
+	pub fn map<U>(self, f: impl FnOnce(T) -> U) -> BunqResponse<U> {
+		BunqResponse {
+			body: match self.body {
+				ApiResponseBody::Ok(body) => ApiResponseBody::Ok(f(body)),
+				ApiResponseBody::Err(errors) => ApiResponseBody::Err(errors),
+			},
+			status_code: self.status_code,
+			response_id: self.response_id,
+			raw_body: self.raw_body,
+			retries: self.retries,
+			retry_after: self.retry_after,
+			request_id: self.request_id,
+		}
+	}
+
+	/// Returns the successful body, or the error response with its body type
+	/// changed to `U`.
+	pub(crate) fn value_or_error<U>(self) -> Result<T, BunqResponse<U>> {
+		match self.body {
+			ApiResponseBody::Ok(value) => Ok(value),
+			ApiResponseBody::Err(errors) => Err(BunqResponse {
+				body: ApiResponseBody::Err(errors),
+				status_code: self.status_code,
+				response_id: self.response_id,
+				raw_body: self.raw_body,
+				retries: self.retries,
+				retry_after: self.retry_after,
+				request_id: self.request_id,
+			}),
+		}
+	}
+
+	/// Returns the exact response body as sent by Bunq.
+	///
+	/// Only available when raw bodies are kept (see
+	/// [`Messenger::set_keep_raw_body`]); `None` otherwise.
+	pub fn raw_body(&self) -> Option<&Bytes> {
+		self.raw_body.as_ref()
+	}
+
 	/// Converts the response into a `Result`.
 	///
 	/// Returns `Ok(T)` for a successful response or
@@ -60,47 +304,522 @@ impl<T> ApiResponse<T> {
 			ApiResponseBody::Err(api_error_response) => Err(ApiErrorResponse {
 				status_code: self.status_code,
 				reasons: api_error_response,
+				retries: self.retries,
+				retry_after: self.retry_after,
+				request_id: Some(self.request_id),
+				response_id: self.response_id,
 			}),
 		}
 	}
 }
 
+impl<T> BunqResponse<Multiple<T>> {
+	/// Returns the pagination cursors of a successful list response.
+	pub fn pagination(&self) -> Option<&Pagination> {
+		self.value().map(|multiple| &multiple.pagination)
+	}
+}
+
 /// Errors that can occur while sending or receiving a message.
+///
+/// Variants about a received response carry its status code, headers and, if
+/// it could be read, its body, so the application can log what Bunq sent. The
+/// headers are boxed to keep the error small.
 #[derive(Debug)]
 pub enum MessageError {
-	/// The response had no body (only the status code is available).
-	NoResponseBody(StatusCode),
-	/// The response body could not be deserialised. A `data_dump.json` file
-	/// is written to the working directory for debugging.
-	BodyParseError,
+	/// The body of the response could not be read.
+	NoResponseBody {
+		/// The `X-Bunq-Client-Request-Id` the request was sent with.
+		request_id: String,
+		status_code: StatusCode,
+		headers: Box<HeaderMap>,
+		source: reqwest::Error,
+	},
+	/// The response body could not be deserialised.
+	BodyParseError {
+		/// The `X-Bunq-Client-Request-Id` the request was sent with.
+		request_id: String,
+		status_code: StatusCode,
+		headers: Box<HeaderMap>,
+		/// The body as received, e.g. for the application to log or store.
+		raw: Bytes,
+		/// Why the body did not match the expected model.
+		source: serde_json::Error,
+	},
 	/// The HTTP request could not be sent (e.g. network error).
-	RequestSendError,
+	RequestSendError {
+		/// The `X-Bunq-Client-Request-Id` the request was sent with.
+		request_id: String,
+		source: reqwest::Error,
+	},
 	/// The `X-Bunq-Server-Signature` header is missing, malformed, or does not
 	/// match the response body.
 	InvalidServerSignature {
+		/// The `X-Bunq-Client-Request-Id` the request was sent with.
+		request_id: String,
 		reason: String,
-		/// Raw API response body, for debugging.
-		api_response: String,
+		status_code: StatusCode,
+		headers: Box<HeaderMap>,
+		/// The body as received.
+		raw: Bytes,
+	},
+	/// The response did not echo the `X-Bunq-Client-Request-Id` of the
+	/// request, see [`Messenger::set_verify_request_id`].
+	RequestIdMismatch {
+		/// The ID the request was sent with.
+		sent: String,
+		/// The ID in the response, if any.
+		received: Option<String>,
+		status_code: StatusCode,
+		headers: Box<HeaderMap>,
 	},
 }
 
+impl fmt::Display for MessageError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			MessageError::NoResponseBody { status_code, .. } => {
+				write!(f, "failed to read the body of a {status_code} response")
+			}
+			MessageError::BodyParseError {
+				status_code,
+				source,
+				..
+			} => write!(
+				f,
+				"failed to parse the body of a {status_code} response: {source}"
+			),
+			MessageError::RequestSendError { source, .. } => {
+				write!(f, "failed to send the request: {source}")
+			}
+			MessageError::InvalidServerSignature {
+				reason,
+				status_code,
+				..
+			} => write!(
+				f,
+				"invalid server signature on a {status_code} response: {reason}"
+			),
+			MessageError::RequestIdMismatch { sent, received, .. } => match received {
+				Some(received) => write!(f, "response is for request {received}, not {sent}"),
+				None => write!(f, "response to request {sent} has no request ID"),
+			},
+		}
+	}
+}
+
+impl MessageError {
+	/// Returns the HTTP status code of the response the error is about, if a
+	/// response was received.
+	pub fn status_code(&self) -> Option<StatusCode> {
+		match self {
+			MessageError::NoResponseBody { status_code, .. }
+			| MessageError::BodyParseError { status_code, .. }
+			| MessageError::InvalidServerSignature { status_code, .. }
+			| MessageError::RequestIdMismatch { status_code, .. } => Some(*status_code),
+			MessageError::RequestSendError { .. } => None,
+		}
+	}
+
+	/// Returns the headers of the response the error is about, if a response
+	/// was received.
+	pub fn headers(&self) -> Option<&HeaderMap> {
+		match self {
+			MessageError::NoResponseBody { headers, .. }
+			| MessageError::BodyParseError { headers, .. }
+			| MessageError::InvalidServerSignature { headers, .. }
+			| MessageError::RequestIdMismatch { headers, .. } => Some(&**headers),
+			MessageError::RequestSendError { .. } => None,
+		}
+	}
+
+	/// Returns the body of the response the error is about, if it was read.
+	pub fn raw_body(&self) -> Option<&Bytes> {
+		match self {
+			MessageError::BodyParseError { raw, .. }
+			| MessageError::InvalidServerSignature { raw, .. } => Some(raw),
+			_ => None,
+		}
+	}
+
+	/// Returns the `X-Bunq-Client-Request-Id` the request was sent with, to
+	/// reference the request in support tickets.
+	///
+	/// ```
+	/// use bunqers::messenger::{Messenger, RequestOptions};
+	/// use bunqers::types::Empty;
+	/// use openssl::{pkey::PKey, rsa::Rsa};
+	/// use reqwest::{Method, header::{HeaderName, HeaderValue}};
+	///
+	/// // Nothing listens on port 1, so the request cannot be sent.
+	/// let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+	/// let mut messenger = Messenger::new("http://127.0.0.1:1".into(), "my-app".into(), key, None, None);
+	/// messenger.set_retry_policy(None);
+	/// let options = RequestOptions::default().header(
+	///     HeaderName::from_static("x-bunq-client-request-id"),
+	///     HeaderValue::from_static("my-request"),
+	/// );
+	///
+	/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+	/// let error = messenger
+	///     .send_with_options::<Empty>(Method::GET, "user", None, &options)
+	///     .await
+	///     .unwrap_err();
+	/// assert_eq!(error.request_id(), "my-request");
+	/// # });
+	/// ```
+	pub fn request_id(&self) -> &str {
+		match self {
+			MessageError::NoResponseBody { request_id, .. }
+			| MessageError::BodyParseError { request_id, .. }
+			| MessageError::RequestSendError { request_id, .. }
+			| MessageError::InvalidServerSignature { request_id, .. }
+			| MessageError::RequestIdMismatch {
+				sent: request_id, ..
+			} => request_id,
+		}
+	}
+
+	/// Returns the `X-Bunq-Client-Response-Id` of the response the error is
+	/// about, if a response was received. Bunq support asks for it when
+	/// investigating an error.
+	pub fn response_id(&self) -> Option<&str> {
+		self.headers()?
+			.get(RESPONSE_ID_HEADER)
+			.and_then(|value| value.to_str().ok())
+	}
+}
+
+impl Error for MessageError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			MessageError::NoResponseBody { source, .. }
+			| MessageError::RequestSendError { source, .. } => Some(source),
+			MessageError::BodyParseError { source, .. } => Some(source),
+			_ => None,
+		}
+	}
+}
+
+/// Per-request settings, see [`Messenger::send_with_options`].
+///
+/// Headers added here are set after the messenger's own headers and replace
+/// them, e.g. to send a custom `X-Bunq-Client-Request-Id` or the
+/// `Content-Type` of an attachment.
+///
+/// ```
+/// use bunqers::messenger::RequestOptions;
+/// use reqwest::header::{HeaderName, HeaderValue};
+///
+/// let options = RequestOptions::new().header(
+///     HeaderName::from_static("x-bunq-attachment-description"),
+///     HeaderValue::from_static("Receipt"),
+/// );
+/// assert_eq!(options.headers().len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+	headers: HeaderMap,
+}
+
+impl RequestOptions {
+	/// Creates options that leave the request unchanged.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Sets the header `name` to `value`, replacing any earlier value.
+	pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+		self.headers.insert(name, value);
+		self
+	}
+
+	/// Sends `geolocation` as `X-Bunq-Geolocation` with this request,
+	/// replacing the messenger's default.
+	pub fn geolocation(self, geolocation: &Geolocation) -> Self {
+		self.header(GEOLOCATION_HEADER, geolocation.header_value())
+	}
+
+	/// Returns the headers added to the request.
+	pub fn headers(&self) -> &HeaderMap {
+		&self.headers
+	}
+}
+
+/// Where a request is made from, sent as the `X-Bunq-Geolocation` header.
+///
+/// Some merchant and tab endpoints expect this header. Use
+/// [`Geolocation::unknown`] when the location is not known.
+///
+/// ```
+/// use bunqers::messenger::Geolocation;
+///
+/// assert_eq!(Geolocation::unknown().to_string(), "0 0 0 0 000");
+///
+/// let amsterdam = Geolocation {
+///     longitude: 4.89,
+///     latitude: 52.37,
+///     altitude: 0.0,
+///     radius: 100.0,
+///     country: "NL".to_string(),
+/// };
+/// assert_eq!(amsterdam.to_string(), "4.89 52.37 0 100 NL");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Geolocation {
+	pub longitude: f64,
+	pub latitude: f64,
+	/// Altitude in meters.
+	pub altitude: f64,
+	/// Accuracy of the location in meters.
+	pub radius: f64,
+	/// ISO 3166-1 alpha-2 country code, or `"000"` if unknown.
+	pub country: String,
+}
+
+impl Geolocation {
+	/// A location that tells Bunq it is unknown, `0 0 0 0 000`.
+	pub fn unknown() -> Self {
+		Self {
+			longitude: 0.0,
+			latitude: 0.0,
+			altitude: 0.0,
+			radius: 0.0,
+			country: "000".to_string(),
+		}
+	}
+
+	/// Returns the location in the format of the `X-Bunq-Geolocation` header.
+	fn header_value(&self) -> HeaderValue {
+		HeaderValue::from_str(&self.to_string())
+			.unwrap_or_else(|_| HeaderValue::from_static("0 0 0 0 000"))
+	}
+}
+
+impl fmt::Display for Geolocation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{} {} {} {} {}",
+			self.longitude, self.latitude, self.altitude, self.radius, self.country
+		)
+	}
+}
+
+/// Product token of this library in the `User-Agent`, so HTTP logs show the
+/// library version. Unless another `User-Agent` is set with
+/// [`Messenger::set_user_agent`], it is sent after the app name, as in
+/// `"my-app bunqers/0.1.0"`.
+pub const DEFAULT_USER_AGENT: &str = concat!("bunqers/", env!("CARGO_PKG_VERSION"));
+
+/// Returns the default `User-Agent` for `app_name`: the app name followed by
+/// [`DEFAULT_USER_AGENT`], or just the latter if the name is empty or cannot
+/// be sent in a header.
+fn default_user_agent(app_name: &str) -> String {
+	let user_agent = format!("{} {DEFAULT_USER_AGENT}", app_name.trim());
+	if app_name.trim().is_empty() || validation::validate_header_value(&user_agent).is_err() {
+		DEFAULT_USER_AGENT.to_string()
+	} else {
+		user_agent
+	}
+}
+
+/// Wait before the first retry of a rate-limited request whose response has
+/// no `Retry-After` header; Bunq's limits apply to windows of 3 seconds.
+pub const RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(3);
+
+/// Header that tells Bunq where a request is made from.
+const GEOLOCATION_HEADER: HeaderName = HeaderName::from_static("x-bunq-geolocation");
+
+/// Header with Bunq's ID for a response, which Bunq support asks for.
+const RESPONSE_ID_HEADER: &str = "x-bunq-client-response-id";
+
+/// Header that identifies a request. Bunq handles a `POST` request with a
+/// given ID at most once, so it is safe to send again.
+const CLIENT_REQUEST_ID_HEADER: &str = "x-bunq-client-request-id";
+
+/// When requests that failed transiently are sent again, see
+/// [`Messenger::set_retry_policy`].
+///
+/// Network errors and HTTP 5xx responses are retried for `GET` requests and
+/// for `POST` requests that carry an `X-Bunq-Client-Request-Id` header (see
+/// [`RequestOptions::header`]); other requests could be carried out twice.
+/// The wait starts at `initial_backoff`, doubles with every attempt up to
+/// `max_backoff`, and is shortened by a random amount of up to half so
+/// clients that failed together do not retry together.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use bunqers::messenger::RetryPolicy;
+///
+/// let policy = RetryPolicy {
+///     max_retries: 5,
+///     ..RetryPolicy::default()
+/// };
+/// assert_eq!(policy.initial_backoff, Duration::from_millis(500));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+	/// How often a request is sent again at most.
+	pub max_retries: u32,
+	/// Wait before the first retry.
+	pub initial_backoff: Duration,
+	/// Longest wait between two attempts.
+	pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self {
+			max_retries: 3,
+			initial_backoff: Duration::from_millis(500),
+			max_backoff: Duration::from_secs(30),
+		}
+	}
+}
+
+impl RetryPolicy {
+	/// Returns the wait before retry number `retries + 1`, or `None` if no
+	/// retries are left.
+	fn backoff(&self, retries: u32) -> Option<Duration> {
+		if retries >= self.max_retries {
+			return None;
+		}
+		let backoff = self
+			.initial_backoff
+			.saturating_mul(1 << retries.min(16))
+			.min(self.max_backoff);
+		Some(backoff.mul_f64(random_factor(0.5, 1.0)))
+	}
+}
+
+/// Callback for sessions created by a [`Messenger`], see
+/// [`Messenger::set_session_observer`].
+pub type SessionObserver = Arc<dyn Fn(&BunqSession) + Send + Sync>;
+
+/// The token a [`Messenger`] authenticates with.
+#[derive(Clone)]
+struct Authentication {
+	token: Option<String>,
+	session_created_at: Option<DateTime<Utc>>,
+	/// Seconds the session stays valid after its creation.
+	session_timeout: Option<i32>,
+	/// Whether the session was closed with
+	/// [`clear_authentication_token`](Messenger::clear_authentication_token).
+	/// Stops all clones from creating new sessions on their own.
+	logged_out: bool,
+}
+
+/// Credentials that let a [`Messenger`] create a new session on its own.
+///
+/// The `Debug` output masks both fields; use
+/// [`reveal`](SessionCredentials::reveal) to include them.
+#[derive(Clone)]
+pub struct SessionCredentials {
+	/// Token issued by the `/installation` endpoint.
+	pub installation_token: String,
+	/// The Bunq API key the device was registered with.
+	pub bunq_api_key: String,
+}
+
+impl SessionCredentials {
+	/// Returns a value whose `Debug` output includes the secrets.
+	pub fn reveal(&self) -> Revealed<'_, Self> {
+		Revealed(self)
+	}
+
+	fn fmt_debug(&self, f: &mut fmt::Formatter<'_>, reveal: bool) -> fmt::Result {
+		f.debug_struct("SessionCredentials")
+			.field(
+				"installation_token",
+				&Secret(&self.installation_token, reveal),
+			)
+			.field("bunq_api_key", &Secret(&self.bunq_api_key, reveal))
+			.finish()
+	}
+}
+
+impl fmt::Debug for SessionCredentials {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.fmt_debug(f, false)
+	}
+}
+
+impl fmt::Debug for Revealed<'_, SessionCredentials> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt_debug(f, true)
+	}
+}
+
+/// How strictly response bodies are matched against the typed models.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParsingMode {
+	/// Fields that are not modelled by this library are ignored.
+	#[default]
+	Lenient,
+	/// Fields that are not modelled by this library make parsing fail with
+	/// [`MessageError::BodyParseError`]. Useful in CI against the sandbox to
+	/// notice when Bunq adds fields.
+	Strict,
+}
+
 /// Handles all HTTP communication with the Bunq API.
 ///
 /// Attach to a [`crate::client_builder::ClientBuilder`] via
 /// [`Messenger::new`]. The authentication token and Bunq's public key are
 /// updated as the builder advances through its state machine.
+///
+/// Cloning is cheap: clones share the HTTP connection pool and the current
+/// session, so a token renewed through one clone is used by all of them.
+/// Settings such as the parsing mode are copied and can be changed per clone.
+#[derive(Clone)]
 pub struct Messenger {
 	base_url: String,
+	/// Name of the application using this messenger, sent at the start of
+	/// the default `User-Agent`.
 	app_name: String,
+	/// Sent as `User-Agent`.
+	user_agent: String,
 	http_client: reqwest::Client,
 	/// RSA private key used to sign outgoing request bodies.
-	private_sign_key: PKey<Private>,
+	private_sign_key: PrivateKey,
 	/// Bunq's RSA public key used to verify incoming response signatures.
 	/// `None` before the `/installation` step completes.
-	bunq_public_sign_key: Option<PKey<Public>>,
-	/// Token sent as `X-Bunq-Client-Authentication`.
-	/// `None` before the first endpoint is called.
-	authentication_token: Option<String>,
+	bunq_public_sign_key: Option<PublicKey>,
+	/// Token sent as `X-Bunq-Client-Authentication`, with the lifetime of
+	/// the session it belongs to. Shared between clones.
+	authentication: Arc<RwLock<Authentication>>,
+	/// Used to create new sessions. `None` disables automatic renewal.
+	session_credentials: Option<SessionCredentials>,
+	/// Whether requests rejected for a missing or expired session are
+	/// retried once with a new session.
+	retry_rejected_sessions: bool,
+	/// How long before its expiry the session is renewed, if at all.
+	session_renewal_margin: Option<Duration>,
+	/// Called with every session the messenger switches to on its own.
+	session_observer: Option<SessionObserver>,
+	parsing_mode: ParsingMode,
+	/// Whether responses keep a copy of their raw body.
+	keep_raw_body: bool,
+	/// Delays requests that would exceed Bunq's rate limits. Shared with
+	/// every messenger the limiter was given to.
+	rate_limiter: Option<RateLimiter>,
+	/// How often a rate-limited request is sent again before its response
+	/// is returned.
+	rate_limit_retries: u32,
+	/// When transiently failed requests are sent again. `None` disables
+	/// retries.
+	retry_policy: Option<RetryPolicy>,
+	/// Whether responses must echo the `X-Bunq-Client-Request-Id` of their
+	/// request.
+	verify_request_id: bool,
+	/// Sent as `X-Bunq-Geolocation` unless a request sets its own.
+	geolocation: Option<Geolocation>,
+	/// Sent as `X-Bunq-Language`, e.g. `nl_NL`.
+	language: Option<String>,
+	/// Sent as `X-Bunq-Region`, e.g. `nl_NL`.
+	region: Option<String>,
 }
 
 impl Messenger {
@@ -114,54 +833,427 @@ impl Messenger {
 	pub fn new(
 		base_url: String,
 		app_name: String,
-		private_sign_key: PKey<Private>,
-		bunq_public_sign_key: Option<PKey<Public>>,
+		private_sign_key: PrivateKey,
+		bunq_public_sign_key: Option<PublicKey>,
 		authentication_token: Option<String>,
 	) -> Self {
 		Self {
-			base_url,
+			// Endpoints are appended as `{base_url}/{endpoint}`.
+			base_url: base_url.trim_end_matches('/').to_string(),
+			user_agent: default_user_agent(&app_name),
 			app_name,
 			http_client: reqwest::Client::new(),
 			private_sign_key,
 			bunq_public_sign_key,
-			authentication_token,
+			authentication: Arc::new(RwLock::new(Authentication {
+				token: authentication_token,
+				session_created_at: None,
+				session_timeout: None,
+				logged_out: false,
+			})),
+			session_credentials: None,
+			retry_rejected_sessions: false,
+			session_renewal_margin: None,
+			session_observer: None,
+			parsing_mode: ParsingMode::default(),
+			keep_raw_body: false,
+			rate_limiter: None,
+			rate_limit_retries: 0,
+			retry_policy: None,
+			verify_request_id: false,
+			geolocation: None,
+			language: None,
+			region: None,
 		}
 	}
 
+	/// Replaces the HTTP client used to send requests, e.g. with one that has
+	/// a timeout or goes through a proxy.
+	pub fn set_http_client(&mut self, http_client: reqwest::Client) {
+		self.http_client = http_client;
+	}
+
+	/// Returns the HTTP client used to send requests.
+	pub fn http_client(&self) -> &reqwest::Client {
+		&self.http_client
+	}
+
+	/// Returns the name of the application this messenger was created for.
+	pub fn app_name(&self) -> &str {
+		&self.app_name
+	}
+
+	/// Sets the `User-Agent` header, replacing the default of app name and
+	/// [`DEFAULT_USER_AGENT`].
+	///
+	/// Returns [`ValidationError::InvalidHeaderValue`] and keeps the previous
+	/// value if `user_agent` cannot be sent in a header.
+	pub fn set_user_agent(&mut self, user_agent: impl Into<String>) -> Result<(), ValidationError> {
+		let user_agent = user_agent.into();
+		validation::validate_header_value(&user_agent)?;
+		self.user_agent = user_agent;
+		Ok(())
+	}
+
+	/// Returns the `User-Agent` header sent with every request.
+	///
+	/// ```
+	/// use bunqers::messenger::{DEFAULT_USER_AGENT, Messenger};
+	/// use openssl::{pkey::PKey, rsa::Rsa};
+	///
+	/// let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+	/// let messenger = Messenger::new("https://example.com".into(), "my-app".into(), key, None, None);
+	/// assert_eq!(messenger.user_agent(), format!("my-app {DEFAULT_USER_AGENT}"));
+	/// ```
+	pub fn user_agent(&self) -> &str {
+		&self.user_agent
+	}
+
+	/// Sets how strictly response bodies are parsed.
+	pub fn set_parsing_mode(&mut self, parsing_mode: ParsingMode) {
+		self.parsing_mode = parsing_mode;
+	}
+
+	/// Returns how strictly response bodies are parsed.
+	pub fn parsing_mode(&self) -> ParsingMode {
+		self.parsing_mode
+	}
+
 	/// Sets the token sent as `X-Bunq-Client-Authentication`.
+	///
+	/// The lifetime of the previous session is forgotten; set the new one with
+	/// [`set_session_lifetime`](Self::set_session_lifetime).
 	pub fn set_authentication_token(&mut self, authentication_token: Option<String>) {
-		self.authentication_token = authentication_token;
+		*self
+			.authentication
+			.write()
+			.unwrap_or_else(PoisonError::into_inner) = Authentication {
+			token: authentication_token,
+			session_created_at: None,
+			session_timeout: None,
+			logged_out: false,
+		};
+	}
+
+	/// Overwrites the authentication token with zeros and stops sending it,
+	/// for this messenger and all its clones. The credentials for creating
+	/// new sessions are wiped as well.
+	///
+	/// Clones keep their own [`SessionCredentials`], but none of them creates
+	/// a new session on its own anymore until a token is set again with
+	/// [`set_authentication_token`](Self::set_authentication_token):
+	///
+	/// ```
+	/// use std::{
+	///     io::{BufRead, BufReader, Write},
+	///     net::TcpListener,
+	///     sync::{Arc, Mutex},
+	///     thread,
+	/// };
+	///
+	/// use base64::{Engine, engine::general_purpose};
+	/// use bunqers::{
+	///     messenger::{Messenger, SessionCredentials},
+	///     types::Empty,
+	/// };
+	/// use openssl::{hash::MessageDigest, pkey::PKey, rsa::Rsa, sign::Signer};
+	/// use reqwest::Method;
+	///
+	/// // A server that rejects every request for a missing session and
+	/// // records the request lines.
+	/// let server_key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+	/// let server_public_key = PKey::public_key_from_pem(&server_key.public_key_to_pem().unwrap()).unwrap();
+	/// let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	/// let base_url = format!("http://{}", listener.local_addr().unwrap());
+	/// let requests = Arc::new(Mutex::new(Vec::new()));
+	/// let recorded = Arc::clone(&requests);
+	/// thread::spawn(move || {
+	///     for stream in listener.incoming() {
+	///         let mut stream = stream.unwrap();
+	///         let mut reader = BufReader::new(stream.try_clone().unwrap());
+	///         let mut line = String::new();
+	///         reader.read_line(&mut line).unwrap();
+	///         recorded.lock().unwrap().push(line.trim().to_string());
+	///         while line != "\r\n" {
+	///             line.clear();
+	///             reader.read_line(&mut line).unwrap();
+	///         }
+	///
+	///         let body = r#"{"Error":[{"error_description":"Insufficient authentication.","error_description_translated":""}]}"#;
+	///         let mut signer = Signer::new(MessageDigest::sha256(), &server_key).unwrap();
+	///         signer.update(body.as_bytes()).unwrap();
+	///         let signature = general_purpose::STANDARD.encode(signer.sign_to_vec().unwrap());
+	///         write!(
+	///             stream,
+	///             "HTTP/1.1 401 Unauthorized\r\nContent-Length: {}\r\nX-Bunq-Server-Signature: {signature}\r\nConnection: close\r\n\r\n{body}",
+	///             body.len(),
+	///         )
+	///         .unwrap();
+	///     }
+	/// });
+	///
+	/// let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+	/// let mut messenger = Messenger::new(base_url, "my-app".into(), key, Some(server_public_key), Some("token".into()));
+	/// let mut clone = messenger.clone();
+	/// clone.set_session_credentials(Some(SessionCredentials {
+	///     installation_token: "installation".into(),
+	///     bunq_api_key: "api-key".into(),
+	/// }));
+	/// clone.set_retry_rejected_sessions(true);
+	///
+	/// messenger.clear_authentication_token();
+	///
+	/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+	/// let response = clone.send::<Empty>(Method::GET, "user", None).await.unwrap();
+	/// assert!(response.into_result().is_err());
+	/// # });
+	/// // The clone did not try to create a new session.
+	/// assert_eq!(*requests.lock().unwrap(), ["GET /user HTTP/1.1"]);
+	/// ```
+	pub fn clear_authentication_token(&mut self) {
+		let mut authentication = self
+			.authentication
+			.write()
+			.unwrap_or_else(PoisonError::into_inner);
+		authentication.token.zeroize();
+		authentication.session_created_at = None;
+		authentication.session_timeout = None;
+		authentication.logged_out = true;
+		drop(authentication);
+
+		if let Some(mut credentials) = self.session_credentials.take() {
+			credentials.installation_token.zeroize();
+			credentials.bunq_api_key.zeroize();
+		}
+	}
+
+	/// Returns the token currently sent as `X-Bunq-Client-Authentication`.
+	///
+	/// This changes when a new session is created automatically, see
+	/// [`set_session_credentials`](Self::set_session_credentials).
+	pub fn authentication_token(&self) -> Option<String> {
+		self.authentication().token
+	}
+
+	/// Sets when the current session was created and how many seconds it
+	/// stays valid, used to renew it ahead of time.
+	pub fn set_session_lifetime(
+		&mut self,
+		session_created_at: Option<DateTime<Utc>>,
+		session_timeout: Option<i32>,
+	) {
+		let mut authentication = self
+			.authentication
+			.write()
+			.unwrap_or_else(PoisonError::into_inner);
+		authentication.session_created_at = session_created_at;
+		authentication.session_timeout = session_timeout;
+	}
+
+	/// Returns when the current session was created, if known.
+	pub fn session_created_at(&self) -> Option<DateTime<Utc>> {
+		self.authentication().session_created_at
+	}
+
+	/// Returns how many seconds the current session stays valid, if known.
+	pub fn session_timeout(&self) -> Option<i32> {
+		self.authentication().session_timeout
+	}
+
+	/// Sets the credentials used to create new sessions automatically, or
+	/// removes them with `None`.
+	///
+	/// Sessions are only created automatically if
+	/// [`set_retry_rejected_sessions`](Self::set_retry_rejected_sessions) or
+	/// [`set_session_renewal_margin`](Self::set_session_renewal_margin) is
+	/// enabled as well.
+	pub fn set_session_credentials(&mut self, session_credentials: Option<SessionCredentials>) {
+		self.session_credentials = session_credentials;
+	}
+
+	/// Returns the credentials used to create new sessions automatically.
+	pub fn session_credentials(&self) -> Option<&SessionCredentials> {
+		self.session_credentials.as_ref()
+	}
+
+	/// Sets whether a request that Bunq rejects because the session is
+	/// missing or expired makes the messenger create a new session and retry
+	/// the request once with the new token. Off by default.
+	pub fn set_retry_rejected_sessions(&mut self, retry_rejected_sessions: bool) {
+		self.retry_rejected_sessions = retry_rejected_sessions;
+	}
+
+	/// Sets how long before its expiry the session is renewed, or disables
+	/// proactive renewal with `None`. Off by default.
+	///
+	/// The check runs before every request, so the request that crosses the
+	/// margin waits for the new session. Sessions with an unknown lifetime
+	/// are never renewed ahead of time.
+	pub fn set_session_renewal_margin(&mut self, session_renewal_margin: Option<Duration>) {
+		self.session_renewal_margin = session_renewal_margin;
+	}
+
+	/// Sets a callback that is called with every session the messenger
+	/// creates on its own, e.g. to persist the new token. Replaces any
+	/// previous callback.
+	pub fn set_session_observer(&mut self, session_observer: Option<SessionObserver>) {
+		self.session_observer = session_observer;
+	}
+
+	/// Returns a snapshot of the current authentication state.
+	fn authentication(&self) -> Authentication {
+		self.authentication
+			.read()
+			.unwrap_or_else(PoisonError::into_inner)
+			.clone()
 	}
 
 	/// Sets Bunq's RSA public key used to verify response signatures.
-	pub fn set_bunq_public_sign_key(&mut self, bunq_public_sign_key: Option<PKey<Public>>) {
+	pub fn set_bunq_public_sign_key(&mut self, bunq_public_sign_key: Option<PublicKey>) {
 		self.bunq_public_sign_key = bunq_public_sign_key;
 	}
 
+	/// Sets whether responses keep the exact body bytes next to the parsed
+	/// value, e.g. to archive server responses for auditing.
+	pub fn set_keep_raw_body(&mut self, keep_raw_body: bool) {
+		self.keep_raw_body = keep_raw_body;
+	}
+
+	/// Returns whether responses keep the exact body bytes.
+	pub fn keeps_raw_body(&self) -> bool {
+		self.keep_raw_body
+	}
+
+	/// Sets the limiter that requests wait for before they are sent, or
+	/// disables client-side rate limiting with `None`. Off by default.
+	pub fn set_rate_limiter(&mut self, rate_limiter: Option<RateLimiter>) {
+		self.rate_limiter = rate_limiter;
+	}
+
+	/// Returns the limiter that requests wait for before they are sent.
+	pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+		self.rate_limiter.as_ref()
+	}
+
+	/// Sets how often a request that Bunq rejects with HTTP 429 is sent
+	/// again. Off (`0`) by default.
+	///
+	/// Before each retry the messenger waits as long as the `Retry-After`
+	/// header asks, or otherwise [`RATE_LIMIT_BACKOFF`], doubled with every
+	/// attempt. If the last attempt is still rate limited, its response is
+	/// returned with [`BunqResponse::retries`] set.
+	pub fn set_rate_limit_retries(&mut self, retries: u32) {
+		self.rate_limit_retries = retries;
+	}
+
+	/// Returns how often a rate-limited request is sent again.
+	pub fn rate_limit_retries(&self) -> u32 {
+		self.rate_limit_retries
+	}
+
+	/// Sets when requests that failed with a network error or HTTP 5xx are
+	/// sent again, or disables this with `None`. Off by default.
+	pub fn set_retry_policy(&mut self, retry_policy: Option<RetryPolicy>) {
+		self.retry_policy = retry_policy;
+	}
+
+	/// Returns when requests that failed transiently are sent again.
+	pub fn retry_policy(&self) -> Option<RetryPolicy> {
+		self.retry_policy
+	}
+
+	/// Sets whether a response that does not echo the
+	/// `X-Bunq-Client-Request-Id` of its request is rejected with
+	/// [`MessageError::RequestIdMismatch`]. Off by default.
+	///
+	/// Every request is sent with a request ID: the one set through
+	/// [`RequestOptions::header`], or else a new random UUID.
+	pub fn set_verify_request_id(&mut self, verify_request_id: bool) {
+		self.verify_request_id = verify_request_id;
+	}
+
+	/// Returns whether responses must echo the ID of their request.
+	pub fn verifies_request_id(&self) -> bool {
+		self.verify_request_id
+	}
+
+	/// Sets the location sent as `X-Bunq-Geolocation` with every request, or
+	/// stops sending the header with `None`. A location set through
+	/// [`RequestOptions::geolocation`] takes precedence.
+	pub fn set_geolocation(&mut self, geolocation: Option<Geolocation>) {
+		self.geolocation = geolocation;
+	}
+
+	/// Returns the location sent as `X-Bunq-Geolocation` with every request.
+	pub fn geolocation(&self) -> Option<&Geolocation> {
+		self.geolocation.as_ref()
+	}
+
+	/// Sets the language Bunq uses for texts such as
+	/// [`ApiErrorDescription::translated`], as a locale like `nl_NL`. `None`
+	/// leaves the choice to Bunq, which defaults to `en_US`.
+	///
+	/// Returns [`ValidationError::InvalidHeaderValue`] and keeps the previous
+	/// value if `language` cannot be sent in a header.
+	///
+	/// ```
+	/// use bunqers::messenger::Messenger;
+	/// use openssl::{pkey::PKey, rsa::Rsa};
+	///
+	/// let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+	/// let mut messenger = Messenger::new("https://example.com".into(), "my-app".into(), key, None, None);
+	/// messenger.set_language(Some("nl_NL".into())).unwrap();
+	/// assert!(messenger.set_language(Some("nl_NL\n".into())).is_err());
+	/// assert_eq!(messenger.language(), Some("nl_NL"));
+	/// ```
+	pub fn set_language(&mut self, language: Option<String>) -> Result<(), ValidationError> {
+		if let Some(language) = &language {
+			validation::validate_header_value(language)?;
+		}
+		self.language = language;
+		Ok(())
+	}
+
+	/// Returns the language sent as `X-Bunq-Language`.
+	pub fn language(&self) -> Option<&str> {
+		self.language.as_deref()
+	}
+
+	/// Sets the region Bunq formats amounts and dates for, as a locale like
+	/// `nl_NL`. `None` leaves the choice to Bunq, which defaults to `en_US`.
+	///
+	/// Returns [`ValidationError::InvalidHeaderValue`] and keeps the previous
+	/// value if `region` cannot be sent in a header.
+	pub fn set_region(&mut self, region: Option<String>) -> Result<(), ValidationError> {
+		if let Some(region) = &region {
+			validation::validate_header_value(region)?;
+		}
+		self.region = region;
+		Ok(())
+	}
+
+	/// Returns the region sent as `X-Bunq-Region`.
+	pub fn region(&self) -> Option<&str> {
+		self.region.as_deref()
+	}
+
 	/// Signs `body` with the client's RSA private key (SHA-256) and returns
 	/// the result as a Base64-encoded string.
 	fn sign_body(&self, body: &str) -> String {
-		let mut signer = Signer::new(
-			openssl::hash::MessageDigest::sha256(),
-			&self.private_sign_key,
-		)
-		.expect("Failed to create Signer with key");
-
-		signer
-			.update(body.as_bytes())
-			.expect("Failed to add body to signer");
-
-		let signature = signer.sign_to_vec().expect("Failed to sign body");
+		let signature =
+			crypto::sign(&self.private_sign_key, body.as_bytes()).expect("Failed to sign body");
 
 		general_purpose::STANDARD.encode(signature)
 	}
 
-	/// Writes raw bytes to `path`. Called when response parsing fails so the
-	/// raw JSON can be inspected.
-	fn dump_json_to_file(contents: &[u8], path: &str) -> std::io::Result<()> {
-		let mut file = File::create(path)?;
-		file.write_all(contents)?;
-		Ok(())
+	/// Parses a response body according to the configured [`ParsingMode`].
+	fn parse_body<T>(&self, body: &[u8]) -> serde_json::Result<T>
+	where
+		T: DeserializeOwned,
+	{
+		with_strict_parsing(self.parsing_mode == ParsingMode::Strict, || {
+			serde_json::from_slice(body)
+		})
 	}
 
 	/// Sends a request **without** verifying the response signature.
@@ -174,30 +1266,33 @@ impl Messenger {
 		method: Method,
 		endpoint: &str,
 		body: Option<String>,
-	) -> Result<ApiResponse<T>, MessageError>
+	) -> Result<BunqResponse<T>, MessageError>
 	where
 		T: DeserializeOwned,
 	{
-		let unverified_response = self.send_http_request(method, endpoint, body).await?;
+		let (unverified_response, request_id) = self
+			.send_http_request(
+				method,
+				endpoint,
+				body,
+				self.authentication_token(),
+				&RequestOptions::default(),
+			)
+			.await?;
 
-		let response_code = unverified_response.status();
-		let response_body = unverified_response
-			.bytes()
-			.await
-			.map_err(|_| MessageError::NoResponseBody(response_code))?;
-
-		let response_body: ApiResponseBody<T> =
-			serde_json::from_slice(&response_body).map_err(|error| {
-				println!("Encountered parsing error: {error}");
-				println!("Dumping file to: data_dump.json");
-				Self::dump_json_to_file(&response_body, "data_dump.json")
-					.expect("Failed to dump JSON to file");
-				MessageError::BodyParseError
-			})?;
-
-		Ok(ApiResponse {
-			body: response_body,
+		let (response_code, headers, response_body) =
+			Self::read_response(unverified_response, &request_id).await?;
+		let parsed_body: ApiResponseBody<T> =
+			self.parse_response_body(&request_id, response_code, &headers, &response_body)?;
+
+		Ok(BunqResponse {
+			body: parsed_body,
 			status_code: response_code,
+			response_id: Self::response_id(&headers),
+			raw_body: self.keep_raw_body.then_some(response_body),
+			retries: 0,
+			retry_after: Self::retry_after(&headers),
+			request_id,
 		})
 	}
 
@@ -208,20 +1303,12 @@ impl Messenger {
 			.decode(signature)
 			.expect("Failed to decode Bunq's signature");
 
-		let mut verifier = Verifier::new(
-			MessageDigest::sha256(),
-			self.bunq_public_sign_key
-				.as_ref()
-				.expect("Missing Bunq's public key to verify signature"),
-		)
-		.expect("Failed to create signature verifier");
-
-		verifier
-			.update(body)
-			.expect("Failed to pass response body to signature verifier");
+		let bunq_public_sign_key = self
+			.bunq_public_sign_key
+			.as_ref()
+			.expect("Missing Bunq's public key to verify signature");
 
-		verifier
-			.verify(&decoded_signature)
+		crypto::verify(bunq_public_sign_key, body, &decoded_signature)
 			.expect("Failed to check API response's signature")
 	}
 
@@ -230,64 +1317,462 @@ impl Messenger {
 	///
 	/// Returns [`MessageError::InvalidServerSignature`] if the header is
 	/// missing or the signature does not match.
+	///
+	/// If automatic re-authentication is enabled and the session was
+	/// rejected, a new session is created and the request is retried once.
 	pub async fn send<T>(
 		&self,
 		method: Method,
 		endpoint: &str,
 		body: Option<String>,
-	) -> Result<ApiResponse<T>, MessageError>
+	) -> Result<BunqResponse<T>, MessageError>
+	where
+		T: DeserializeOwned + std::fmt::Debug,
+	{
+		self.send_with_options(method, endpoint, body, &RequestOptions::default())
+			.await
+	}
+
+	/// Like [`send`](Self::send), with extra per-request `options`.
+	pub async fn send_with_options<T>(
+		&self,
+		method: Method,
+		endpoint: &str,
+		body: Option<String>,
+		options: &RequestOptions,
+	) -> Result<BunqResponse<T>, MessageError>
 	where
 		T: DeserializeOwned + std::fmt::Debug,
 	{
-		let unverified_response = self
-			.send_http_request(method, endpoint, body.clone())
+		if self.session_expiring() {
+			self.renew_session().await;
+		}
+
+		let send = || {
+			self.send_verified(
+				method.clone(),
+				endpoint,
+				body.clone(),
+				self.authentication_token(),
+				options,
+			)
+		};
+		let response = self.send_with_retries(&method, options, send).await?;
+
+		if self.session_rejected(&response) && self.renew_session().await {
+			return self.send_with_retries(&method, options, send).await;
+		}
+
+		Ok(response)
+	}
+
+	/// Sends a request with the given authentication token and verifies the
+	/// signature on the response. The session is never renewed.
+	pub(crate) async fn send_verified<T>(
+		&self,
+		method: Method,
+		endpoint: &str,
+		body: Option<String>,
+		authentication_token: Option<String>,
+		options: &RequestOptions,
+	) -> Result<BunqResponse<T>, MessageError>
+	where
+		T: DeserializeOwned + std::fmt::Debug,
+	{
+		let (unverified_response, request_id) = self
+			.send_http_request(method, endpoint, body, authentication_token, options)
 			.await?;
 
-		let server_signature = unverified_response
-			.headers()
-			.get("X-Bunq-Server-Signature")
-			.cloned();
-		let response_code = unverified_response.status();
-		let response_body = unverified_response
-			.bytes()
-			.await
-			.map_err(|_| MessageError::NoResponseBody(response_code))?;
-
-		let api_response_body: ApiResponseBody<T> = serde_json::from_slice(&response_body)
-			.map_err(|error| {
-				println!("Encountered parsing error: {error}");
-				println!("Dumping file to: data_dump.json");
-				Self::dump_json_to_file(&response_body, "data_dump.json")
-					.expect("Failed to dump JSON to file");
-				MessageError::BodyParseError
-			})?;
-
-		let api_response = ApiResponse {
+		let (response_code, headers, response_body) =
+			Self::read_response(unverified_response, &request_id).await?;
+		self.check_server_signature(&request_id, response_code, &headers, &response_body)?;
+
+		let api_response_body: ApiResponseBody<T> =
+			self.parse_response_body(&request_id, response_code, &headers, &response_body)?;
+
+		Ok(BunqResponse {
 			body: api_response_body,
 			status_code: response_code,
+			response_id: Self::response_id(&headers),
+			raw_body: self.keep_raw_body.then_some(response_body),
+			retries: 0,
+			retry_after: Self::retry_after(&headers),
+			request_id,
+		})
+	}
+
+	/// Sends a request whose successful response is not JSON (e.g. a PNG
+	/// image) and verifies the `X-Bunq-Server-Signature` on the response.
+	///
+	/// On a successful status the raw body bytes are returned as-is. Error
+	/// responses are still parsed as regular Bunq `Error` bodies. Rejected
+	/// sessions are renewed as in [`send`](Self::send).
+	pub async fn send_raw(
+		&self,
+		method: Method,
+		endpoint: &str,
+		body: Option<String>,
+	) -> Result<BunqResponse<Vec<u8>>, MessageError> {
+		self.send_raw_with_options(method, endpoint, body, &RequestOptions::default())
+			.await
+	}
+
+	/// Like [`send_raw`](Self::send_raw), with extra per-request `options`.
+	pub async fn send_raw_with_options(
+		&self,
+		method: Method,
+		endpoint: &str,
+		body: Option<String>,
+		options: &RequestOptions,
+	) -> Result<BunqResponse<Vec<u8>>, MessageError> {
+		if self.session_expiring() {
+			self.renew_session().await;
+		}
+
+		let send = || {
+			self.send_raw_verified(
+				method.clone(),
+				endpoint,
+				body.clone(),
+				self.authentication_token(),
+				options,
+			)
 		};
+		let response = self.send_with_retries(&method, options, send).await?;
 
-		// Verify the response signature before returning.
-		let body_signature = server_signature
-			.ok_or_else(|| MessageError::InvalidServerSignature {
-				reason: "No X-Bunq-Server-Signature header in response".to_string(),
-				api_response: format!("{:?}", api_response),
-			})?
-			.to_str()
-			.map_err(|_| MessageError::InvalidServerSignature {
-				reason: "X-Bunq-Server-Signature header contained non-ASCII bytes".to_string(),
-				api_response: format!("{:?}", api_response),
-			})?
-			.to_string();
+		if self.session_rejected(&response) && self.renew_session().await {
+			return self.send_with_retries(&method, options, send).await;
+		}
+
+		Ok(response)
+	}
+
+	/// Sends a request with the given authentication token and returns the
+	/// verified raw body.
+	async fn send_raw_verified(
+		&self,
+		method: Method,
+		endpoint: &str,
+		body: Option<String>,
+		authentication_token: Option<String>,
+		options: &RequestOptions,
+	) -> Result<BunqResponse<Vec<u8>>, MessageError> {
+		let (unverified_response, request_id) = self
+			.send_http_request(method, endpoint, body, authentication_token, options)
+			.await?;
+
+		let (response_code, headers, response_body) =
+			Self::read_response(unverified_response, &request_id).await?;
+		// Verify the response signature before interpreting the body.
+		self.check_server_signature(&request_id, response_code, &headers, &response_body)?;
+
+		let body = if response_code.is_success() {
+			ApiResponseBody::Ok(response_body.to_vec())
+		} else {
+			match self.parse_response_body::<Empty>(
+				&request_id,
+				response_code,
+				&headers,
+				&response_body,
+			)? {
+				ApiResponseBody::Err(errors) => ApiResponseBody::Err(errors),
+				ApiResponseBody::Ok(_) => {
+					return Err(MessageError::BodyParseError {
+						request_id,
+						status_code: response_code,
+						headers: Box::new(headers),
+						raw: response_body,
+						source: serde::de::Error::custom(format!(
+							"expected an error body for status {response_code}"
+						)),
+					});
+				}
+			}
+		};
+
+		Ok(BunqResponse {
+			body,
+			status_code: response_code,
+			response_id: Self::response_id(&headers),
+			raw_body: self.keep_raw_body.then_some(response_body),
+			retries: 0,
+			retry_after: Self::retry_after(&headers),
+			request_id,
+		})
+	}
+
+	/// Returns `true` if proactive renewal is enabled and the current
+	/// session expires within the renewal margin.
+	fn session_expiring(&self) -> bool {
+		let Some(margin) = self.session_renewal_margin else {
+			return false;
+		};
+		let authentication = self.authentication();
+		if authentication.logged_out {
+			return false;
+		}
+		let (Some(created_at), Some(timeout)) = (
+			authentication.session_created_at,
+			authentication.session_timeout,
+		) else {
+			return false;
+		};
+
+		let expires_at = created_at + TimeDelta::seconds(timeout.into());
+		let margin = TimeDelta::from_std(margin).unwrap_or(TimeDelta::MAX);
+		self.session_credentials.is_some()
+			&& Utc::now()
+				.checked_add_signed(margin)
+				.is_none_or(|now| now >= expires_at)
+	}
+
+	/// Returns `true` if rejected sessions are retried and Bunq rejected the
+	/// request because of a missing or expired session.
+	fn session_rejected<T>(&self, response: &BunqResponse<T>) -> bool {
+		self.retry_rejected_sessions
+			&& self.session_credentials.is_some()
+			&& !self.authentication().logged_out
+			&& matches!(
+				Self::error_kind(response),
+				Some(BunqErrorKind::InsufficientAuthentication | BunqErrorKind::SessionExpired)
+			)
+	}
+
+	/// Classifies the error in `response`, or returns `None` if the request
+	/// succeeded.
+	fn error_kind<T>(response: &BunqResponse<T>) -> Option<BunqErrorKind> {
+		let ApiResponseBody::Err(reasons) = &response.body else {
+			return None;
+		};
+		let error = ApiErrorResponse {
+			status_code: response.status_code,
+			reasons: reasons.clone(),
+			retries: 0,
+			retry_after: None,
+			request_id: None,
+			response_id: None,
+		};
+		Some(error.kind())
+	}
+
+	/// Sends a request with `send` and sends it again after a wait while
+	/// Bunq rejects it for exceeding the rate limit, up to
+	/// [`rate_limit_retries`](Self::rate_limit_retries) times, or while it
+	/// fails transiently, as allowed by the [`RetryPolicy`].
+	async fn send_with_retries<T, F, R>(
+		&self,
+		method: &Method,
+		options: &RequestOptions,
+		send: F,
+	) -> Result<BunqResponse<T>, MessageError>
+	where
+		F: Fn() -> R,
+		R: Future<Output = Result<BunqResponse<T>, MessageError>>,
+	{
+		// Only requests that Bunq handles at most once may be repeated after
+		// a failure that could have happened after Bunq acted on them.
+		let idempotent = *method == Method::GET
+			|| (*method == Method::POST && options.headers.contains_key(CLIENT_REQUEST_ID_HEADER));
+		let retry_policy = self.retry_policy.filter(|_| idempotent);
+
+		let mut rate_limit_retries = 0;
+		let mut transient_retries = 0;
+		loop {
+			let result = send().await;
+			let transient = match &result {
+				Ok(BunqResponse { status_code, .. })
+				| Err(MessageError::NoResponseBody { status_code, .. }) => status_code.is_server_error(),
+				Err(MessageError::RequestSendError { .. }) => true,
+				Err(_) => false,
+			};
+
+			let wait = match &result {
+				Ok(response)
+					if rate_limit_retries < self.rate_limit_retries
+						&& Self::error_kind(response) == Some(BunqErrorKind::RateLimited) =>
+				{
+					let backoff = RATE_LIMIT_BACKOFF.saturating_mul(1 << rate_limit_retries.min(5));
+					rate_limit_retries += 1;
+					response.retry_after.unwrap_or(backoff)
+				}
+				_ if transient => {
+					match retry_policy.and_then(|policy| policy.backoff(transient_retries)) {
+						Some(backoff) => {
+							transient_retries += 1;
+							backoff
+						}
+						None => break result,
+					}
+				}
+				_ => break result,
+			};
+			Sleep::until(Instant::now() + wait).await;
+		}
+		.map(|mut response| {
+			response.retries = rate_limit_retries + transient_retries;
+			response
+		})
+	}
+
+	/// Creates a new session with the stored [`SessionCredentials`] and
+	/// switches to its token. Returns `false` if no session could be created
+	/// or the session was closed in the meantime.
+	async fn renew_session(&self) -> bool {
+		let Some(credentials) = &self.session_credentials else {
+			return false;
+		};
+		let response = self.create_session(credentials).await;
+		let Ok(Ok(session)) = response.map(BunqResponse::into_result) else {
+			return false;
+		};
+
+		self.replace_session(&session, false)
+	}
+
+	/// Creates a new session with `credentials` without switching to it.
+	pub(crate) async fn create_session(
+		&self,
+		credentials: &SessionCredentials,
+	) -> Result<BunqResponse<BunqSession>, MessageError> {
+		let body = serde_json::to_string(&CreateSession {
+			bunq_api_key: credentials.bunq_api_key.clone(),
+		})
+		.expect("Failed to serialize session request");
+
+		self.send_verified(
+			Method::POST,
+			"session-server",
+			Some(body),
+			Some(credentials.installation_token.clone()),
+			&RequestOptions::default(),
+		)
+		.await
+	}
+
+	/// Authenticates subsequent requests with `session` and reports it to the
+	/// session observer.
+	pub(crate) fn switch_session(&self, session: &BunqSession) {
+		self.replace_session(session, true);
+	}
+
+	/// Switches to `session` like [`switch_session`](Self::switch_session).
+	/// Unless `after_logout` is set, a session that was closed with
+	/// [`clear_authentication_token`](Self::clear_authentication_token) is
+	/// not replaced and `false` is returned.
+	fn replace_session(&self, session: &BunqSession, after_logout: bool) -> bool {
+		let mut authentication = self
+			.authentication
+			.write()
+			.unwrap_or_else(PoisonError::into_inner);
+		if authentication.logged_out && !after_logout {
+			return false;
+		}
+		*authentication = Authentication {
+			token: Some(session.token.token.clone()),
+			session_created_at: Some(session.token.created.and_utc()),
+			session_timeout: Some(session.user_person.session_timeout),
+			logged_out: false,
+		};
+		drop(authentication);
+
+		if let Some(session_observer) = &self.session_observer {
+			session_observer(session);
+		}
+		true
+	}
+
+	/// Reads the `Retry-After` header of a response, given either in seconds
+	/// or as an HTTP date.
+	fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+		let value = headers.get("Retry-After")?.to_str().ok()?.trim();
+		if let Ok(seconds) = value.parse() {
+			return Some(Duration::from_secs(seconds));
+		}
+		let date = DateTime::parse_from_rfc2822(value).ok()?;
+		Some(
+			(date.with_timezone(&Utc) - Utc::now())
+				.to_std()
+				.unwrap_or_default(),
+		)
+	}
+
+	/// Reads the `X-Bunq-Client-Response-Id` header of a response.
+	fn response_id(headers: &HeaderMap) -> Option<String> {
+		headers
+			.get(RESPONSE_ID_HEADER)
+			.and_then(|value| value.to_str().ok())
+			.map(str::to_string)
+	}
 
-		if !self.verify_body_signature(&body_signature, &response_body) {
-			return Err(MessageError::InvalidServerSignature {
-				reason: "X-Bunq-Server-Signature did not match the response body".to_string(),
-				api_response: format!("{:?}", api_response),
-			});
+	/// Reads the status code, headers and body of `response`.
+	async fn read_response(
+		response: reqwest::Response,
+		request_id: &str,
+	) -> Result<(StatusCode, HeaderMap, Bytes), MessageError> {
+		let status_code = response.status();
+		let headers = response.headers().clone();
+		match response.bytes().await {
+			Ok(body) => Ok((status_code, headers, body)),
+			Err(source) => Err(MessageError::NoResponseBody {
+				request_id: request_id.to_string(),
+				status_code,
+				headers: Box::new(headers),
+				source,
+			}),
 		}
+	}
 
-		Ok(api_response)
+	/// Parses a response body, keeping the response in the error if it does
+	/// not match `T`.
+	fn parse_response_body<T>(
+		&self,
+		request_id: &str,
+		status_code: StatusCode,
+		headers: &HeaderMap,
+		body: &Bytes,
+	) -> Result<ApiResponseBody<T>, MessageError>
+	where
+		T: DeserializeOwned,
+	{
+		self.parse_body(body)
+			.map_err(|source| MessageError::BodyParseError {
+				request_id: request_id.to_string(),
+				status_code,
+				headers: Box::new(headers.clone()),
+				raw: body.clone(),
+				source,
+			})
+	}
+
+	/// Checks the `X-Bunq-Server-Signature` header of a response against its
+	/// body.
+	fn check_server_signature(
+		&self,
+		request_id: &str,
+		status_code: StatusCode,
+		headers: &HeaderMap,
+		body: &Bytes,
+	) -> Result<(), MessageError> {
+		let invalid = |reason: &str| MessageError::InvalidServerSignature {
+			request_id: request_id.to_string(),
+			reason: reason.to_string(),
+			status_code,
+			headers: Box::new(headers.clone()),
+			raw: body.clone(),
+		};
+
+		let signature = headers
+			.get("X-Bunq-Server-Signature")
+			.ok_or_else(|| invalid("No X-Bunq-Server-Signature header in response"))?
+			.to_str()
+			.map_err(|_| invalid("X-Bunq-Server-Signature header contained non-ASCII bytes"))?;
+
+		if !self.verify_body_signature(signature, body) {
+			return Err(invalid(
+				"X-Bunq-Server-Signature did not match the response body",
+			));
+		}
+		Ok(())
 	}
 
 	/// Builds and executes the raw HTTP request, returning the unprocessed
@@ -297,12 +1782,18 @@ impl Messenger {
 		method: Method,
 		endpoint: &str,
 		body: Option<String>,
-	) -> Result<reqwest::Response, MessageError> {
+		authentication_token: Option<String>,
+		options: &RequestOptions,
+	) -> Result<(reqwest::Response, String), MessageError> {
+		if let Some(rate_limiter) = &self.rate_limiter {
+			rate_limiter.acquire(&method, endpoint).await;
+		}
+
 		let url = format!("{}/{}", self.base_url, endpoint);
 		let mut request = self
 			.http_client
 			.request(method, url)
-			.header("User-Agent", self.app_name.clone())
+			.header("User-Agent", self.user_agent.as_str())
 			.header("Cache-Control", "no-cache");
 
 		// Sign the body and attach the signature header.
@@ -314,15 +1805,88 @@ impl Messenger {
 		}
 
 		// Attach the authentication token if one is available.
-		if let Some(authentication_token) = &self.authentication_token {
+		if let Some(authentication_token) = authentication_token {
 			request = request.header("X-Bunq-Client-Authentication", authentication_token);
 		}
 
-		let request = request.build().expect("Failed to build HTTP request");
+		if let Some(geolocation) = &self.geolocation {
+			request = request.header(GEOLOCATION_HEADER, geolocation.header_value());
+		}
+		if let Some(language) = &self.language {
+			request = request.header("X-Bunq-Language", language);
+		}
+		if let Some(region) = &self.region {
+			request = request.header("X-Bunq-Region", region);
+		}
 
-		self.http_client
-			.execute(request)
-			.await
-			.map_err(|_| MessageError::RequestSendError)
+		let mut request = request.build().expect("Failed to build HTTP request");
+		// Per-request headers replace the defaults set above.
+		request.headers_mut().extend(options.headers.clone());
+
+		let request_id = match request.headers().get(CLIENT_REQUEST_ID_HEADER) {
+			Some(request_id) => String::from_utf8_lossy(request_id.as_bytes()).into_owned(),
+			None => {
+				let request_id = new_request_id();
+				request.headers_mut().insert(
+					CLIENT_REQUEST_ID_HEADER,
+					HeaderValue::from_str(&request_id).expect("UUIDs are valid header values"),
+				);
+				request_id
+			}
+		};
+
+		let response = self.http_client.execute(request).await.map_err(|source| {
+			MessageError::RequestSendError {
+				request_id: request_id.clone(),
+				source,
+			}
+		})?;
+
+		if self.verify_request_id {
+			let received = response
+				.headers()
+				.get(CLIENT_REQUEST_ID_HEADER)
+				.and_then(|value| value.to_str().ok());
+			if received != Some(request_id.as_str()) {
+				return Err(MessageError::RequestIdMismatch {
+					received: received.map(str::to_string),
+					sent: request_id,
+					status_code: response.status(),
+					headers: Box::new(response.headers().clone()),
+				});
+			}
+		}
+
+		Ok((response, request_id))
 	}
 }
+
+/// Returns a random factor between `min` and `max`, e.g. to spread the
+/// retries of many clients over time.
+pub(crate) fn random_factor(min: f64, max: f64) -> f64 {
+	let mut bytes = [0; 4];
+	// Without randomness the middle of the range still gives a sensible delay.
+	if crypto::random_bytes(&mut bytes).is_err() {
+		return (min + max) / 2.0;
+	}
+	let fraction = f64::from(u32::from_le_bytes(bytes)) / (f64::from(u32::MAX) + 1.0);
+	min + (max - min) * fraction
+}
+
+/// Returns a random (version 4) UUID to use as `X-Bunq-Client-Request-Id`.
+fn new_request_id() -> String {
+	let mut bytes = [0; 16];
+	crypto::random_bytes(&mut bytes).expect("Failed to generate a request ID");
+	bytes[6] = (bytes[6] & 0x0f) | 0x40;
+	bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+	let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+	format!(
+		"{}-{}-{}-{}-{}",
+		&hex[..8],
+		&hex[8..12],
+		&hex[12..16],
+		&hex[16..20],
+		&hex[20..]
+	)
+}
@@ -0,0 +1,34 @@
+//! Commonly used types, for glob importing.
+//!
+//! ```
+//! use bunqers::prelude::*;
+//!
+//! let amount = Amount::new(Decimal::new(1250, 2), Currency::Eur);
+//! assert_eq!(amount.value, Decimal::new(1250, 2));
+//! ```
+//!
+//! Besides this crate's own types, the prelude re-exports the `openssl` and
+//! `rust_decimal` types that appear in public signatures, so downstream
+//! crates can name them without depending on matching versions of those
+//! crates.
+
+#[cfg(all(feature = "openssl", not(target_arch = "wasm32")))]
+pub use openssl::pkey::{PKey, Private, Public};
+pub use rust_decimal::Decimal;
+
+pub use crate::{
+	InstallationContext,
+	client::{Client, SessionContext},
+	client_builder::{
+		ApiVersion, AuthenticationError, BuildError, BuildErrorReason, ClientBuilder, Environment,
+		Installed, Registered, UncheckedSession,
+	},
+	crypto::{PrivateKey, PublicKey},
+	messenger::{
+		ApiErrorResponse, BunqErrorKind, BunqResponse, MessageError, ParsingMode, RetryPolicy,
+	},
+	rate_limiter::{RateLimiter, RateLimits},
+	session_state::SessionState,
+	signing_key::{KeySpec, SigningKey},
+	types::{Amount, Currency, MonetaryAccountId, Multiple, PaymentId, Single, UserId},
+};
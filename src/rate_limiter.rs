@@ -0,0 +1,237 @@
+//! Client-side rate limiting that matches Bunq's limits.
+//!
+//! Bunq allows about 3 `GET`, 5 `POST` and 2 `PUT` requests per endpoint
+//! within any 3 seconds and answers anything beyond that with HTTP 429. A
+//! [`RateLimiter`] attached to a [`Messenger`](crate::messenger::Messenger)
+//! delays requests that would exceed these limits instead, so bursts are
+//! queued rather than rejected.
+//!
+//! Clones of a limiter share their state, so one limiter can be shared by all
+//! clients that use the same API key:
+//!
+//! ```no_run
+//! # fn example(mut client: bunqers::client::Client, mut other: bunqers::client::Client) {
+//! use bunqers::rate_limiter::RateLimiter;
+//!
+//! let limiter = RateLimiter::default();
+//! client.set_rate_limiter(Some(limiter.clone()));
+//! other.set_rate_limiter(Some(limiter));
+//! # }
+//! ```
+
+use std::{
+	collections::{HashMap, VecDeque},
+	mem,
+	sync::{Arc, Mutex, PoisonError},
+	time::Duration,
+};
+
+use reqwest::Method;
+
+use crate::sleep::{Instant, Sleep};
+
+/// How many requests may be sent within a period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+	pub requests: u32,
+	pub period: Duration,
+}
+
+impl RateLimit {
+	/// Allows `requests` requests within any `period`. A limit of zero
+	/// requests or a zero period disables limiting.
+	pub const fn new(requests: u32, period: Duration) -> Self {
+		Self { requests, period }
+	}
+
+	fn is_enabled(&self) -> bool {
+		self.requests > 0 && !self.period.is_zero()
+	}
+}
+
+/// The limit per HTTP method, applied to each endpoint separately.
+///
+/// The defaults follow Bunq's documented limits. Bunq does not document a
+/// limit for `DELETE`, so it is treated like `PUT`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimits {
+	pub get: RateLimit,
+	pub post: RateLimit,
+	pub put: RateLimit,
+	pub delete: RateLimit,
+}
+
+impl Default for RateLimits {
+	fn default() -> Self {
+		const PERIOD: Duration = Duration::from_secs(3);
+		Self {
+			get: RateLimit::new(3, PERIOD),
+			post: RateLimit::new(5, PERIOD),
+			put: RateLimit::new(2, PERIOD),
+			delete: RateLimit::new(2, PERIOD),
+		}
+	}
+}
+
+impl RateLimits {
+	/// Returns the limit for `method`, or `None` if it is not limited.
+	fn for_method(&self, method: &Method) -> Option<RateLimit> {
+		let limit = match *method {
+			Method::GET => self.get,
+			Method::POST => self.post,
+			Method::PUT => self.put,
+			Method::DELETE => self.delete,
+			_ => return None,
+		};
+		limit.is_enabled().then_some(limit)
+	}
+}
+
+/// Delays requests so they stay within [`RateLimits`].
+///
+/// Every endpoint has a bucket with one token per allowed request. Sending a
+/// request takes a token, which returns to the bucket one period later; a
+/// request that finds the bucket empty waits for the next token. Requests to
+/// the same endpoint are therefore sent in the order they were made.
+///
+/// Endpoints are told apart by their path without query string, so
+/// `GET user/1/monetary-account/2/payment` and
+/// `GET user/1/monetary-account/3/payment` are limited separately.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+	limits: RateLimits,
+	windows: Arc<Mutex<HashMap<(Method, String), Window>>>,
+}
+
+/// The times at which the tokens of one bucket were, or will be, taken.
+#[derive(Debug)]
+struct Window {
+	period: Duration,
+	slots: VecDeque<Instant>,
+}
+
+impl RateLimiter {
+	/// Creates a limiter with custom limits, e.g. stricter ones to leave room
+	/// for other processes that use the same API key.
+	pub fn new(limits: RateLimits) -> Self {
+		Self {
+			limits,
+			windows: Arc::default(),
+		}
+	}
+
+	/// Returns the limits this limiter enforces.
+	pub fn limits(&self) -> RateLimits {
+		self.limits
+	}
+
+	/// Waits until a request to `endpoint` may be sent. If the wait is
+	/// cancelled by dropping the future, the token is returned.
+	pub(crate) async fn acquire(&self, method: &Method, endpoint: &str) {
+		if let Some(slot) = self.reserve(method, endpoint, Instant::now()) {
+			let reservation = Reservation {
+				limiter: self,
+				method,
+				endpoint,
+				slot,
+			};
+			Sleep::until(slot).await;
+			mem::forget(reservation);
+		}
+	}
+
+	/// Takes a token for a request to `endpoint` at `now` and returns when it
+	/// may be sent, or `None` if it may be sent right away. The token counts
+	/// as used from then on, whether or not the request is sent.
+	///
+	/// ```
+	/// use std::time::{Duration, Instant};
+	///
+	/// use bunqers::rate_limiter::{RateLimit, RateLimiter, RateLimits};
+	/// use reqwest::Method;
+	///
+	/// let period = Duration::from_secs(3);
+	/// let limiter = RateLimiter::new(RateLimits {
+	///     get: RateLimit::new(2, period),
+	///     ..RateLimits::default()
+	/// });
+	/// let start = Instant::now();
+	/// let at = |seconds| start + Duration::from_secs(seconds);
+	///
+	/// // Two tokens per period; the query string does not matter.
+	/// assert_eq!(limiter.reserve(&Method::GET, "user", at(0)), None);
+	/// assert_eq!(limiter.reserve(&Method::GET, "user?count=10", at(1)), None);
+	/// // The third request waits until the first token returns.
+	/// assert_eq!(limiter.reserve(&Method::GET, "user", at(1)), Some(at(3)));
+	/// // Other endpoints and methods have their own buckets.
+	/// assert_eq!(limiter.reserve(&Method::GET, "user/1/card", at(1)), None);
+	/// assert_eq!(limiter.reserve(&Method::POST, "user", at(1)), None);
+	/// // The requests from `at(1)` and `at(3)` still hold their tokens.
+	/// assert_eq!(limiter.reserve(&Method::GET, "user", at(3)), Some(at(4)));
+	/// assert_eq!(limiter.reserve(&Method::GET, "user", at(10)), None);
+	/// ```
+	pub fn reserve(&self, method: &Method, endpoint: &str, now: Instant) -> Option<Instant> {
+		let limit = self.limits.for_method(method)?;
+		let path = endpoint.split('?').next().unwrap_or(endpoint);
+
+		let mut windows = self.windows.lock().unwrap_or_else(PoisonError::into_inner);
+		// Forget endpoints whose tokens have all returned.
+		windows.retain(|_, window| {
+			window
+				.slots
+				.back()
+				.is_some_and(|last| *last + window.period > now)
+		});
+
+		let window = windows
+			.entry((method.clone(), path.to_string()))
+			.or_insert_with(|| Window {
+				period: limit.period,
+				slots: VecDeque::new(),
+			});
+		while window
+			.slots
+			.front()
+			.is_some_and(|first| *first + limit.period <= now)
+		{
+			window.slots.pop_front();
+		}
+
+		let taken = window.slots.len();
+		let requests = limit.requests as usize;
+		let slot = if taken < requests {
+			now
+		} else {
+			(window.slots[taken - requests] + limit.period).max(now)
+		};
+		window.slots.push_back(slot);
+
+		(slot > now).then_some(slot)
+	}
+
+	/// Returns the token taken for `slot` by [`reserve`](Self::reserve).
+	fn release(&self, method: &Method, endpoint: &str, slot: Instant) {
+		let path = endpoint.split('?').next().unwrap_or(endpoint);
+		let mut windows = self.windows.lock().unwrap_or_else(PoisonError::into_inner);
+		if let Some(window) = windows.get_mut(&(method.clone(), path.to_string()))
+			&& let Some(index) = window.slots.iter().rposition(|taken| *taken == slot)
+		{
+			window.slots.remove(index);
+		}
+	}
+}
+
+/// A token taken for a request that is still waiting for it. Dropping it
+/// returns the token.
+struct Reservation<'a> {
+	limiter: &'a RateLimiter,
+	method: &'a Method,
+	endpoint: &'a str,
+	slot: Instant,
+}
+
+impl Drop for Reservation<'_> {
+	fn drop(&mut self) {
+		self.limiter.release(self.method, self.endpoint, self.slot);
+	}
+}
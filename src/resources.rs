@@ -0,0 +1,365 @@
+//! Resource-oriented access to the endpoints below a monetary account.
+//!
+//! Most endpoints live below `/user/{userId}/monetary-account/{accountId}`.
+//! Instead of passing the account ID to every [`Client`] method, take a
+//! scope once with [`Client::monetary_account`] and navigate from there:
+//!
+//! ```rust,no_run
+//! # async fn example(client: bunqers::client::Client) {
+//! # let account_id = todo!();
+//! let account = client.monetary_account(account_id);
+//!
+//! let payments = account.payments().list().await;
+//! let tab = account
+//!     .bunqme_tabs()
+//!     .create(
+//!         bunqers::types::Amount::new(rust_decimal::Decimal::new(1250, 2), bunqers::types::Currency::Eur),
+//!         "Dinner".into(),
+//!         "https://example.com".into(),
+//!     )
+//!     .await;
+//! # }
+//! ```
+//!
+//! Every method calls the matching [`Client`] method, so the two styles can be
+//! mixed freely.
+
+use crate::{
+	client::Client,
+	messenger::BunqResponse,
+	types::{
+		AlterBunqMeTabResponseWrapper, Amount, BunqMeTabHandle, BunqMeTabId, BunqMeTabStatus,
+		BunqMeTabWrapper, CreateBunqMeTab, CreateCustomerStatementExport,
+		CreateCustomerStatementExportResponseWrapper, CreateDraftPayment,
+		CreateDraftPaymentResponseWrapper, CustomerStatementExportWrapper, DraftPaymentWrapper,
+		MasterCardActionWrapper, MonetaryAccountBankWrapper, MonetaryAccountId, Multiple,
+		PageCursor, PaymentId, PaymentWrapper, RequestInquiryWrapper, ScheduleWrapper, Single,
+	},
+	validation::ValidationError,
+};
+
+impl Client {
+	/// Returns a scope for the endpoints of a single monetary account.
+	pub fn monetary_account(&self, id: MonetaryAccountId) -> MonetaryAccount<'_> {
+		MonetaryAccount { client: self, id }
+	}
+}
+
+/// A single monetary account of the session's user.
+#[derive(Clone, Copy)]
+pub struct MonetaryAccount<'a> {
+	client: &'a Client,
+	id: MonetaryAccountId,
+}
+
+impl<'a> MonetaryAccount<'a> {
+	/// Returns the ID of the account.
+	pub fn id(&self) -> MonetaryAccountId {
+		self.id
+	}
+
+	/// Fetches the account. See [`Client::get_monetary_account`].
+	pub async fn get(&self) -> BunqResponse<Single<MonetaryAccountBankWrapper>> {
+		self.client.get_monetary_account(self.id).await
+	}
+
+	/// Payments made from or to the account.
+	pub fn payments(&self) -> Payments<'a> {
+		Payments {
+			client: self.client,
+			monetary_account_id: self.id,
+		}
+	}
+
+	/// bunq.me payment requests of the account.
+	pub fn bunqme_tabs(&self) -> BunqMeTabs<'a> {
+		BunqMeTabs {
+			client: self.client,
+			monetary_account_id: self.id,
+		}
+	}
+
+	/// Payments that wait for approval in the bunq app.
+	pub fn draft_payments(&self) -> DraftPayments<'a> {
+		DraftPayments {
+			client: self.client,
+			monetary_account_id: self.id,
+		}
+	}
+
+	/// Statement exports of the account.
+	pub fn customer_statements(&self) -> CustomerStatements<'a> {
+		CustomerStatements {
+			client: self.client,
+			monetary_account_id: self.id,
+		}
+	}
+
+	/// Card transactions on the account.
+	pub fn mastercard_actions(&self) -> MasterCardActions<'a> {
+		MasterCardActions {
+			client: self.client,
+			monetary_account_id: self.id,
+		}
+	}
+
+	/// Payment requests sent from the account.
+	pub fn request_inquiries(&self) -> RequestInquiries<'a> {
+		RequestInquiries {
+			client: self.client,
+			monetary_account_id: self.id,
+		}
+	}
+
+	/// Scheduled payments of the account.
+	pub fn schedules(&self) -> Schedules<'a> {
+		Schedules {
+			client: self.client,
+			monetary_account_id: self.id,
+		}
+	}
+}
+
+/// The payments of a monetary account.
+#[derive(Clone, Copy)]
+pub struct Payments<'a> {
+	client: &'a Client,
+	monetary_account_id: MonetaryAccountId,
+}
+
+impl Payments<'_> {
+	/// See [`Client::get_payments`].
+	pub async fn list(&self) -> BunqResponse<Multiple<PaymentWrapper>> {
+		self.client.get_payments(self.monetary_account_id).await
+	}
+
+	/// See [`Client::get_payments_page`].
+	pub async fn list_page(&self, cursor: &PageCursor) -> BunqResponse<Multiple<PaymentWrapper>> {
+		self.client
+			.get_payments_page(self.monetary_account_id, cursor)
+			.await
+	}
+
+	/// See [`Client::get_payment`].
+	pub async fn get(&self, payment_id: PaymentId) -> BunqResponse<Single<PaymentWrapper>> {
+		self.client
+			.get_payment(self.monetary_account_id, payment_id)
+			.await
+	}
+}
+
+/// The bunq.me payment requests of a monetary account.
+#[derive(Clone, Copy)]
+pub struct BunqMeTabs<'a> {
+	client: &'a Client,
+	monetary_account_id: MonetaryAccountId,
+}
+
+impl BunqMeTabs<'_> {
+	/// See [`Client::create_payment_request`].
+	pub async fn create(
+		&self,
+		amount: Amount,
+		description: String,
+		redirect_url: String,
+	) -> Result<BunqResponse<BunqMeTabHandle>, ValidationError> {
+		self.client
+			.create_payment_request(self.monetary_account_id, amount, description, redirect_url)
+			.await
+	}
+
+	/// See [`Client::create_payment_request_with_options`].
+	pub async fn create_with_options(&self, tab: CreateBunqMeTab) -> BunqResponse<BunqMeTabHandle> {
+		self.client
+			.create_payment_request_with_options(self.monetary_account_id, tab)
+			.await
+	}
+
+	/// See [`Client::get_payment_request`].
+	pub async fn get(&self, id: BunqMeTabId) -> BunqResponse<Single<BunqMeTabWrapper>> {
+		self.client
+			.get_payment_request(self.monetary_account_id, id)
+			.await
+	}
+
+	/// See [`Client::update_payment_request`].
+	pub async fn update(
+		&self,
+		id: BunqMeTabId,
+		status: Option<BunqMeTabStatus>,
+		note: Option<String>,
+	) -> BunqResponse<Single<AlterBunqMeTabResponseWrapper>> {
+		self.client
+			.update_payment_request(self.monetary_account_id, id, status, note)
+			.await
+	}
+
+	/// See [`Client::close_payment_request`].
+	pub async fn close(
+		&self,
+		id: BunqMeTabId,
+	) -> BunqResponse<Single<AlterBunqMeTabResponseWrapper>> {
+		self.client
+			.close_payment_request(self.monetary_account_id, id)
+			.await
+	}
+
+	/// See [`Client::reopen_payment_request`].
+	pub async fn reopen(
+		&self,
+		id: BunqMeTabId,
+	) -> BunqResponse<Single<AlterBunqMeTabResponseWrapper>> {
+		self.client
+			.reopen_payment_request(self.monetary_account_id, id)
+			.await
+	}
+}
+
+/// The draft payments of a monetary account.
+#[derive(Clone, Copy)]
+pub struct DraftPayments<'a> {
+	client: &'a Client,
+	monetary_account_id: MonetaryAccountId,
+}
+
+impl DraftPayments<'_> {
+	/// See [`Client::create_draft_payment`].
+	pub async fn create(
+		&self,
+		draft_payment: CreateDraftPayment,
+	) -> BunqResponse<Single<CreateDraftPaymentResponseWrapper>> {
+		self.client
+			.create_draft_payment(self.monetary_account_id, draft_payment)
+			.await
+	}
+
+	/// See [`Client::get_draft_payments`].
+	pub async fn list(&self) -> BunqResponse<Multiple<DraftPaymentWrapper>> {
+		self.client
+			.get_draft_payments(self.monetary_account_id)
+			.await
+	}
+
+	/// See [`Client::get_draft_payment`].
+	pub async fn get(&self, draft_payment_id: u32) -> BunqResponse<Single<DraftPaymentWrapper>> {
+		self.client
+			.get_draft_payment(self.monetary_account_id, draft_payment_id)
+			.await
+	}
+}
+
+/// The statement exports of a monetary account.
+#[derive(Clone, Copy)]
+pub struct CustomerStatements<'a> {
+	client: &'a Client,
+	monetary_account_id: MonetaryAccountId,
+}
+
+impl CustomerStatements<'_> {
+	/// See [`Client::create_customer_statement`].
+	pub async fn create(
+		&self,
+		export: CreateCustomerStatementExport,
+	) -> BunqResponse<Single<CreateCustomerStatementExportResponseWrapper>> {
+		self.client
+			.create_customer_statement(self.monetary_account_id, export)
+			.await
+	}
+
+	/// See [`Client::get_customer_statements`].
+	pub async fn list(&self) -> BunqResponse<Multiple<CustomerStatementExportWrapper>> {
+		self.client
+			.get_customer_statements(self.monetary_account_id)
+			.await
+	}
+
+	/// See [`Client::get_customer_statement`].
+	pub async fn get(
+		&self,
+		customer_statement_id: u32,
+	) -> BunqResponse<Single<CustomerStatementExportWrapper>> {
+		self.client
+			.get_customer_statement(self.monetary_account_id, customer_statement_id)
+			.await
+	}
+
+	/// See [`Client::get_customer_statement_content`].
+	pub async fn content(&self, customer_statement_id: u32) -> BunqResponse<Vec<u8>> {
+		self.client
+			.get_customer_statement_content(self.monetary_account_id, customer_statement_id)
+			.await
+	}
+}
+
+/// The card transactions of a monetary account.
+#[derive(Clone, Copy)]
+pub struct MasterCardActions<'a> {
+	client: &'a Client,
+	monetary_account_id: MonetaryAccountId,
+}
+
+impl MasterCardActions<'_> {
+	/// See [`Client::get_mastercard_actions`].
+	pub async fn list(&self) -> BunqResponse<Multiple<MasterCardActionWrapper>> {
+		self.client
+			.get_mastercard_actions(self.monetary_account_id)
+			.await
+	}
+
+	/// See [`Client::get_mastercard_action`].
+	pub async fn get(
+		&self,
+		mastercard_action_id: u32,
+	) -> BunqResponse<Single<MasterCardActionWrapper>> {
+		self.client
+			.get_mastercard_action(self.monetary_account_id, mastercard_action_id)
+			.await
+	}
+}
+
+/// The payment requests sent from a monetary account.
+#[derive(Clone, Copy)]
+pub struct RequestInquiries<'a> {
+	client: &'a Client,
+	monetary_account_id: MonetaryAccountId,
+}
+
+impl RequestInquiries<'_> {
+	/// See [`Client::get_request_inquiries`].
+	pub async fn list(&self) -> BunqResponse<Multiple<RequestInquiryWrapper>> {
+		self.client
+			.get_request_inquiries(self.monetary_account_id)
+			.await
+	}
+
+	/// See [`Client::get_request_inquiry`].
+	pub async fn get(
+		&self,
+		request_inquiry_id: u32,
+	) -> BunqResponse<Single<RequestInquiryWrapper>> {
+		self.client
+			.get_request_inquiry(self.monetary_account_id, request_inquiry_id)
+			.await
+	}
+}
+
+/// The scheduled payments of a monetary account.
+#[derive(Clone, Copy)]
+pub struct Schedules<'a> {
+	client: &'a Client,
+	monetary_account_id: MonetaryAccountId,
+}
+
+impl Schedules<'_> {
+	/// See [`Client::get_schedules`].
+	pub async fn list(&self) -> BunqResponse<Multiple<ScheduleWrapper>> {
+		self.client.get_schedules(self.monetary_account_id).await
+	}
+
+	/// See [`Client::get_schedule`].
+	pub async fn get(&self, schedule_id: u32) -> BunqResponse<Single<ScheduleWrapper>> {
+		self.client
+			.get_schedule(self.monetary_account_id, schedule_id)
+			.await
+	}
+}
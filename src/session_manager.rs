@@ -0,0 +1,150 @@
+//! Clients for many Bunq users in one process.
+//!
+//! A [`SessionManager`] hands out one rate-limited client per Bunq API key.
+//! Clients are authenticated on first use and keep their session alive on
+//! their own: sessions are renewed shortly before they time out, and requests
+//! rejected for an expired session are retried once with a new one.
+//!
+//! Every user gets their own [`ClientRateLimited`], so one busy user cannot
+//! use up the request quota of the others.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use bunqers::session_manager::SessionManager;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let manager = SessionManager::new(bunqers::PRODUCTION_API_URL, "my-app", "my-server");
+//!
+//! let client_rl = manager.client("api-key-of-some-user").await?;
+//! client_rl.get_user_ratelimited(|result| async move {
+//!     let response = result.expect("rate limit exhausted");
+//!     let user = response.into_result().expect("API error");
+//!     println!("Hello, {}!", user.user_person.display_name);
+//! }).await;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex, PoisonError},
+	time::Duration,
+};
+
+use tokio::sync::OnceCell;
+
+use crate::{
+	client_builder::{AuthenticationError, ClientBuilder},
+	client_rate_limited::ClientRateLimited,
+};
+
+/// How long before their timeout the sessions of managed clients are renewed.
+pub const SESSION_RENEWAL_MARGIN: Duration = Duration::from_secs(60);
+
+/// The lazily authenticated client of a single user.
+type ClientSlot = Arc<OnceCell<Arc<ClientRateLimited>>>;
+
+/// Holds one authenticated, rate-limited client per Bunq API key.
+///
+/// Each client gets its own device installation and key pair, created the
+/// first time [`client`](SessionManager::client) is called for its API key.
+/// Nothing is persisted; a new process installs its devices again.
+pub struct SessionManager {
+	api_base_url: String,
+	app_name: String,
+	device_description: String,
+	max_retries: u32,
+	clients: Mutex<HashMap<String, ClientSlot>>,
+}
+
+impl SessionManager {
+	/// Creates an empty manager whose clients talk to `api_base_url` and
+	/// register their devices as `device_description`.
+	pub fn new(
+		api_base_url: impl Into<String>,
+		app_name: impl Into<String>,
+		device_description: impl Into<String>,
+	) -> Self {
+		Self {
+			api_base_url: api_base_url.into(),
+			app_name: app_name.into(),
+			device_description: device_description.into(),
+			max_retries: 3,
+			clients: Mutex::new(HashMap::new()),
+		}
+	}
+
+	/// Sets how often a rate-limited request of a managed client is retried.
+	/// Defaults to 3.
+	pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+		self.max_retries = max_retries;
+		self
+	}
+
+	/// Returns the client for `bunq_api_key`, authenticating it first if this
+	/// is the first request for that key.
+	///
+	/// Concurrent calls for the same key wait for a single authentication. If
+	/// authentication fails, the error is returned and the next call tries
+	/// again.
+	pub async fn client(
+		&self,
+		bunq_api_key: &str,
+	) -> Result<Arc<ClientRateLimited>, AuthenticationError> {
+		let slot = self
+			.clients
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.entry(bunq_api_key.to_string())
+			.or_default()
+			.clone();
+
+		slot.get_or_try_init(|| self.authenticate(bunq_api_key))
+			.await
+			.cloned()
+	}
+
+	/// Forgets the client for `bunq_api_key`, e.g. after the user revoked the
+	/// key. Returns the client if it was authenticated.
+	pub fn remove(&self, bunq_api_key: &str) -> Option<Arc<ClientRateLimited>> {
+		self.clients
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.remove(bunq_api_key)
+			.and_then(|slot| slot.get().cloned())
+	}
+
+	/// Returns the number of API keys the manager holds a client for.
+	pub fn len(&self) -> usize {
+		self.clients
+			.lock()
+			.unwrap_or_else(PoisonError::into_inner)
+			.values()
+			.filter(|slot| slot.initialized())
+			.count()
+	}
+
+	/// Returns `true` if the manager holds no authenticated clients.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Sets up a new device for `bunq_api_key` and wraps its client with rate
+	/// limiters.
+	async fn authenticate(
+		&self,
+		bunq_api_key: &str,
+	) -> Result<Arc<ClientRateLimited>, AuthenticationError> {
+		let mut client =
+			ClientBuilder::new_without_key(self.api_base_url.as_str(), self.app_name.as_str())
+				.map_err(AuthenticationError::Install)?
+				.authenticate(bunq_api_key, self.device_description.as_str())
+				.await?;
+		client.set_auto_reauthenticate(true);
+		client.set_session_renewal_margin(Some(SESSION_RENEWAL_MARGIN));
+
+		Ok(Arc::new(ClientRateLimited::new(client, self.max_retries)))
+	}
+}
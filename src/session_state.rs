@@ -0,0 +1,402 @@
+//! Versioned persistence format for the credentials gathered during setup.
+//!
+//! Every stage of the [`ClientBuilder`](crate::client_builder::ClientBuilder)
+//! produces credentials that are worth keeping across process restarts: the
+//! client's private key, the installation token, the device registration and
+//! finally the session token. [`SessionState`] collects all of them in one
+//! struct that can be saved after each step and loaded on the next run.
+//!
+//! The JSON layout carries a `version` field. Files written by older versions
+//! of this library are migrated on load, so stored state keeps working after
+//! an upgrade.
+
+use std::{error::Error, fmt, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+	client::SessionContext,
+	client_builder::{Installed, Registered, Revealed, Secret, UncheckedSession},
+	crypto::{self, KeyError, PrivateKey, PublicKey},
+	types::{DeviceId, UserId},
+};
+
+/// Schema version written by [`SessionState::to_json`].
+pub const SESSION_STATE_VERSION: u32 = 1;
+
+/// Everything needed to resume a client from any point of the setup flow.
+///
+/// Fields are filled in as setup progresses; a state that only holds a
+/// private key is as valid as one holding a full session.
+///
+/// ```
+/// use bunqers::session_state::SessionState;
+/// use openssl::{pkey::PKey, rsa::Rsa};
+///
+/// let private_key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+/// let state = SessionState::new(private_key);
+///
+/// let json = state.to_json().unwrap();
+/// let restored = SessionState::from_json(&json).unwrap();
+/// assert!(restored.private_key.unwrap().public_eq(&state.private_key.unwrap()));
+/// assert!(restored.installation_token.is_none());
+/// ```
+///
+/// The `Debug` output masks the private key, the tokens and the API key; use
+/// [`reveal`](SessionState::reveal) to include them.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "Value", into = "StoredSessionState")]
+pub struct SessionState {
+	/// The client's RSA private key, used to sign requests.
+	pub private_key: Option<PrivateKey>,
+	/// Token issued by the `/installation` endpoint.
+	pub installation_token: Option<String>,
+	/// Bunq's RSA public key, used to verify response signatures.
+	pub bunq_public_key: Option<PublicKey>,
+	/// The Bunq API key the device was registered with.
+	pub bunq_api_key: Option<String>,
+	/// Device ID assigned by the `/device-server` endpoint.
+	pub registered_device_id: Option<DeviceId>,
+	/// Token of the most recent session.
+	pub session_token: Option<String>,
+	/// User that owns the most recent session.
+	pub owner_id: Option<UserId>,
+}
+
+impl SessionState {
+	/// Returns a value whose `Debug` output includes the secrets.
+	///
+	/// ```
+	/// use bunqers::session_state::SessionState;
+	/// use openssl::{pkey::PKey, rsa::Rsa};
+	///
+	/// let private_key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+	/// let mut state = SessionState::new(private_key);
+	/// state.bunq_api_key = Some("api-key".into());
+	///
+	/// assert!(!format!("{state:?}").contains("api-key"));
+	/// assert!(format!("{:?}", state.reveal()).contains("api-key"));
+	/// ```
+	pub fn reveal(&self) -> Revealed<'_, Self> {
+		Revealed(self)
+	}
+
+	fn fmt_debug(&self, f: &mut fmt::Formatter<'_>, reveal: bool) -> fmt::Result {
+		f.debug_struct("SessionState")
+			.field("private_key", &Secret::optional(&self.private_key, reveal))
+			.field(
+				"installation_token",
+				&Secret::optional(&self.installation_token, reveal),
+			)
+			.field("bunq_public_key", &self.bunq_public_key)
+			.field(
+				"bunq_api_key",
+				&Secret::optional(&self.bunq_api_key, reveal),
+			)
+			.field("registered_device_id", &self.registered_device_id)
+			.field(
+				"session_token",
+				&Secret::optional(&self.session_token, reveal),
+			)
+			.field("owner_id", &self.owner_id)
+			.finish()
+	}
+}
+
+impl fmt::Debug for SessionState {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.fmt_debug(f, false)
+	}
+}
+
+impl fmt::Debug for Revealed<'_, SessionState> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt_debug(f, true)
+	}
+}
+
+/// The furthest setup stage that can be restored from a [`SessionState`].
+#[derive(Debug)]
+pub enum SessionStage {
+	/// A session token exists but has not been validated yet.
+	Session(UncheckedSession),
+	/// The device is registered; a new session can be created.
+	Registration(Registered),
+	/// The device is installed but not registered.
+	Installation(Installed),
+	/// Only the private key is known; the device must be installed again.
+	KeyOnly,
+}
+
+impl SessionState {
+	/// Creates a state holding only the client's private key.
+	pub fn new(private_key: PrivateKey) -> Self {
+		Self {
+			private_key: Some(private_key),
+			..Default::default()
+		}
+	}
+
+	/// Creates a state from a completed `/installation` step.
+	pub fn from_installation(context: &Installed, private_key: &PrivateKey) -> Self {
+		Self {
+			installation_token: Some(context.installation_token.clone()),
+			bunq_public_key: Some(context.bunq_public_key.clone()),
+			..Self::new(private_key.clone())
+		}
+	}
+
+	/// Creates a state from a completed device registration.
+	pub fn from_registration(context: &Registered, private_key: &PrivateKey) -> Self {
+		Self {
+			installation_token: Some(context.installation_token.clone()),
+			bunq_public_key: Some(context.bunq_public_key.clone()),
+			bunq_api_key: Some(context.bunq_api_key.clone()),
+			registered_device_id: Some(context.registered_device_id),
+			..Self::new(private_key.clone())
+		}
+	}
+
+	/// Creates a state from an active session.
+	pub fn from_session(context: &SessionContext, private_key: &PrivateKey) -> Self {
+		Self {
+			installation_token: Some(context.installation_token.clone()),
+			bunq_public_key: Some(context.bunq_public_key.clone()),
+			bunq_api_key: Some(context.bunq_api_key.clone()),
+			registered_device_id: Some(context.registered_device_id),
+			session_token: Some(context.session_token.clone()),
+			owner_id: Some(context.owner_id),
+			..Self::new(private_key.clone())
+		}
+	}
+
+	/// Splits the state into the furthest restorable [`SessionStage`] and the
+	/// client's private key.
+	///
+	/// Stages with missing fields are skipped in favour of an earlier one.
+	/// Returns `None` if no private key is stored.
+	pub fn into_stage(self) -> Option<(SessionStage, PrivateKey)> {
+		let private_key = self.private_key?;
+
+		let stage = match self {
+			Self {
+				installation_token: Some(installation_token),
+				bunq_public_key: Some(bunq_public_key),
+				bunq_api_key: Some(bunq_api_key),
+				registered_device_id: Some(registered_device_id),
+				session_token,
+				..
+			} => match session_token {
+				Some(session_token) => SessionStage::Session(UncheckedSession {
+					session_token,
+					registered_device_id,
+					bunq_api_key,
+					installation_token,
+					bunq_public_key,
+				}),
+				None => SessionStage::Registration(Registered {
+					registered_device_id,
+					bunq_api_key,
+					installation_token,
+					bunq_public_key,
+				}),
+			},
+			Self {
+				installation_token: Some(installation_token),
+				bunq_public_key: Some(bunq_public_key),
+				..
+			} => SessionStage::Installation(Installed {
+				installation_token,
+				bunq_public_key,
+			}),
+			_ => SessionStage::KeyOnly,
+		};
+
+		Some((stage, private_key))
+	}
+
+	/// Serialises the state as JSON in the current schema version.
+	pub fn to_json(&self) -> Result<String, SessionStateError> {
+		serde_json::to_string_pretty(self).map_err(SessionStateError::Json)
+	}
+
+	/// Parses a state from JSON, migrating older schema versions.
+	///
+	/// State written by a newer version of this library is rejected:
+	///
+	/// ```
+	/// use bunqers::session_state::{SessionState, SessionStateError};
+	///
+	/// let result = SessionState::from_json(r#"{"version": 99}"#);
+	/// assert!(matches!(result, Err(SessionStateError::UnsupportedVersion(99))));
+	/// ```
+	pub fn from_json(json: &str) -> Result<Self, SessionStateError> {
+		let value = serde_json::from_str(json).map_err(SessionStateError::Json)?;
+		Self::from_value(value)
+	}
+
+	/// Writes the state as JSON to `path`, replacing any existing file.
+	pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SessionStateError> {
+		fs::write(path, self.to_json()?).map_err(SessionStateError::Io)
+	}
+
+	/// Reads a state from the JSON file at `path`, migrating older schema
+	/// versions.
+	pub fn load(path: impl AsRef<Path>) -> Result<Self, SessionStateError> {
+		let json = fs::read_to_string(path).map_err(SessionStateError::Io)?;
+		Self::from_json(&json)
+	}
+
+	fn from_value(value: Value) -> Result<Self, SessionStateError> {
+		let stored: StoredSessionState =
+			serde_json::from_value(migrate(value)?).map_err(SessionStateError::Json)?;
+		stored.try_into()
+	}
+}
+
+/// Upgrades a stored state to [`SESSION_STATE_VERSION`], one version at a
+/// time.
+///
+/// Version 0 is the unversioned layout used by earlier examples of this
+/// library; it has the same fields as version 1.
+fn migrate(mut value: Value) -> Result<Value, SessionStateError> {
+	let Some(object) = value.as_object_mut() else {
+		return Err(SessionStateError::Json(serde::de::Error::custom(
+			"session state must be a JSON object",
+		)));
+	};
+
+	let mut version = match object.get("version") {
+		None => 0,
+		Some(version) => version
+			.as_u64()
+			.and_then(|version| u32::try_from(version).ok())
+			.ok_or_else(|| {
+				SessionStateError::Json(serde::de::Error::custom("invalid session state version"))
+			})?,
+	};
+	if version > SESSION_STATE_VERSION {
+		return Err(SessionStateError::UnsupportedVersion(version));
+	}
+
+	while version < SESSION_STATE_VERSION {
+		if version == 0 {
+			object.insert("version".into(), 1.into());
+		}
+		version += 1;
+	}
+
+	Ok(value)
+}
+
+/// On-disk layout of [`SessionState`], with keys stored as PEM strings.
+#[derive(Serialize, Deserialize)]
+struct StoredSessionState {
+	version: u32,
+	private_key: Option<String>,
+	installation_token: Option<String>,
+	bunq_public_key: Option<String>,
+	bunq_api_key: Option<String>,
+	registered_device_id: Option<DeviceId>,
+	session_token: Option<String>,
+	owner_id: Option<UserId>,
+}
+
+impl From<SessionState> for StoredSessionState {
+	fn from(state: SessionState) -> Self {
+		// Converting an in-memory RSA key to PEM does not fail in practice, and
+		// serde's `into` conversion cannot report an error.
+		let to_pem = |pem: Result<Vec<u8>, KeyError>| {
+			String::from_utf8(pem.expect("Failed to serialize key as PEM"))
+				.expect("PEM output contained non-UTF-8 characters")
+		};
+
+		Self {
+			version: SESSION_STATE_VERSION,
+			private_key: state
+				.private_key
+				.map(|key| to_pem(crypto::private_key_to_pem_pkcs8(&key))),
+			installation_token: state.installation_token,
+			bunq_public_key: state
+				.bunq_public_key
+				.map(|key| to_pem(crypto::public_key_to_pem(&key))),
+			bunq_api_key: state.bunq_api_key,
+			registered_device_id: state.registered_device_id,
+			session_token: state.session_token,
+			owner_id: state.owner_id,
+		}
+	}
+}
+
+impl TryFrom<StoredSessionState> for SessionState {
+	type Error = SessionStateError;
+
+	fn try_from(stored: StoredSessionState) -> Result<Self, Self::Error> {
+		Ok(Self {
+			private_key: stored
+				.private_key
+				.map(|pem| crypto::private_key_from_pem(pem.as_bytes()))
+				.transpose()
+				.map_err(SessionStateError::InvalidKey)?,
+			installation_token: stored.installation_token,
+			bunq_public_key: stored
+				.bunq_public_key
+				.map(|pem| crypto::public_key_from_pem(pem.as_bytes()))
+				.transpose()
+				.map_err(SessionStateError::InvalidKey)?,
+			bunq_api_key: stored.bunq_api_key,
+			registered_device_id: stored.registered_device_id,
+			session_token: stored.session_token,
+			owner_id: stored.owner_id,
+		})
+	}
+}
+
+impl TryFrom<Value> for SessionState {
+	type Error = SessionStateError;
+
+	fn try_from(value: Value) -> Result<Self, Self::Error> {
+		Self::from_value(value)
+	}
+}
+
+/// Errors that can occur while loading or saving a [`SessionState`].
+#[derive(Debug)]
+pub enum SessionStateError {
+	/// The state file could not be read or written.
+	Io(io::Error),
+	/// The state is not valid JSON or does not match the schema.
+	Json(serde_json::Error),
+	/// The state was written by a newer version of this library.
+	UnsupportedVersion(u32),
+	/// A stored key is not a valid PEM-encoded RSA key.
+	InvalidKey(KeyError),
+}
+
+impl fmt::Display for SessionStateError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			SessionStateError::Io(error) => write!(f, "failed to access session state: {error}"),
+			SessionStateError::Json(error) => write!(f, "invalid session state: {error}"),
+			SessionStateError::UnsupportedVersion(version) => write!(
+				f,
+				"session state version {version} is newer than the supported version {SESSION_STATE_VERSION}"
+			),
+			SessionStateError::InvalidKey(error) => {
+				write!(f, "invalid key in session state: {error}")
+			}
+		}
+	}
+}
+
+impl Error for SessionStateError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			SessionStateError::Io(error) => Some(error),
+			SessionStateError::Json(error) => Some(error),
+			SessionStateError::UnsupportedVersion(_) => None,
+			SessionStateError::InvalidKey(error) => Some(error),
+		}
+	}
+}
@@ -0,0 +1,156 @@
+//! Private keys the client signs its requests with.
+//!
+//! [`SigningKey`] accepts a key in whatever form the application already has
+//! it: a [`PrivateKey`], PKCS#8 bytes, or, with the `rsa` feature, an
+//! [`rsa::RsaPrivateKey`]. Applications built on a rustls/ring stack can thus
+//! hand over their key without using openssl's types themselves. Signing
+//! itself is done by the backend in [`crypto`]; see there for how to not link
+//! openssl at all.
+
+use std::{error::Error, fmt};
+
+use crate::crypto::{self, KeyError, PrivateKey};
+
+/// Parameters for generating a new [`SigningKey`].
+///
+/// ```
+/// use bunqers::signing_key::{KeySpec, SigningKey};
+///
+/// assert_eq!(KeySpec::default(), KeySpec::Rsa2048);
+///
+/// let key = SigningKey::generate(KeySpec::Rsa3072).unwrap();
+/// assert_eq!(key.as_pkey().bits(), 3072);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum KeySpec {
+	/// 2048-bit RSA, the size Bunq's documentation uses.
+	#[default]
+	Rsa2048,
+	/// 3072-bit RSA.
+	Rsa3072,
+	/// 4096-bit RSA.
+	Rsa4096,
+}
+
+/// An RSA private key used to sign requests to Bunq.
+///
+/// ```
+/// use bunqers::signing_key::SigningKey;
+/// use openssl::{pkey::PKey, rsa::Rsa};
+///
+/// let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+/// let der = key.private_key_to_pkcs8().unwrap();
+///
+/// let signing_key = SigningKey::from_pkcs8_der(&der).unwrap();
+/// assert!(signing_key.as_pkey().public_eq(&key));
+/// ```
+#[derive(Clone)]
+pub struct SigningKey(PrivateKey);
+
+impl SigningKey {
+	/// Generates a new key pair according to `spec`.
+	pub fn generate(spec: KeySpec) -> Result<Self, KeyError> {
+		let bits = match spec {
+			KeySpec::Rsa2048 => 2048,
+			KeySpec::Rsa3072 => 3072,
+			KeySpec::Rsa4096 => 4096,
+		};
+		crypto::generate_private_key(bits).map(Self)
+	}
+
+	/// Parses a DER-encoded PKCS#8 private key.
+	pub fn from_pkcs8_der(der: &[u8]) -> Result<Self, SigningKeyError> {
+		let key = crypto::private_key_from_pkcs8(der).map_err(SigningKeyError::InvalidKey)?;
+		Self::from_pkey(key)
+	}
+
+	/// Parses a PEM-encoded PKCS#8 private key (`BEGIN PRIVATE KEY`).
+	pub fn from_pkcs8_pem(pem: &str) -> Result<Self, SigningKeyError> {
+		let key =
+			crypto::private_key_from_pem(pem.as_bytes()).map_err(SigningKeyError::InvalidKey)?;
+		Self::from_pkey(key)
+	}
+
+	/// Returns the key as a [`PrivateKey`], an openssl `PKey` with the
+	/// `openssl` feature.
+	pub fn as_pkey(&self) -> &PrivateKey {
+		&self.0
+	}
+
+	/// Converts the key into a [`PrivateKey`], an openssl `PKey` with the
+	/// `openssl` feature.
+	pub fn into_pkey(self) -> PrivateKey {
+		self.0
+	}
+
+	/// Checks that `key` is an RSA key, the only kind Bunq accepts.
+	fn from_pkey(key: PrivateKey) -> Result<Self, SigningKeyError> {
+		crypto::check_rsa(&key).map_err(SigningKeyError::InvalidKey)?;
+		Ok(Self(key))
+	}
+}
+
+impl From<PrivateKey> for SigningKey {
+	fn from(key: PrivateKey) -> Self {
+		Self(key)
+	}
+}
+
+impl From<SigningKey> for PrivateKey {
+	fn from(key: SigningKey) -> Self {
+		key.0
+	}
+}
+
+#[cfg(feature = "rsa")]
+impl TryFrom<&rsa::RsaPrivateKey> for SigningKey {
+	type Error = SigningKeyError;
+
+	fn try_from(key: &rsa::RsaPrivateKey) -> Result<Self, Self::Error> {
+		use rsa::pkcs8::EncodePrivateKey;
+
+		let der = key.to_pkcs8_der().map_err(SigningKeyError::Encoding)?;
+		Self::from_pkcs8_der(der.as_bytes())
+	}
+}
+
+impl fmt::Debug for SigningKey {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("SigningKey")
+			.field("bits", &crypto::key_bits(&self.0))
+			.finish_non_exhaustive()
+	}
+}
+
+/// Errors that can occur while converting a key into a [`SigningKey`].
+#[derive(Debug)]
+pub enum SigningKeyError {
+	/// The key could not be parsed, or is not an RSA key.
+	InvalidKey(KeyError),
+	/// The `rsa` key could not be encoded as PKCS#8.
+	#[cfg(feature = "rsa")]
+	Encoding(rsa::pkcs8::Error),
+}
+
+impl fmt::Display for SigningKeyError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			SigningKeyError::InvalidKey(error) => write!(f, "invalid RSA private key: {error}"),
+			#[cfg(feature = "rsa")]
+			SigningKeyError::Encoding(error) => {
+				write!(f, "failed to encode RSA private key: {error}")
+			}
+		}
+	}
+}
+
+impl Error for SigningKeyError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			SigningKeyError::InvalidKey(error) => Some(error),
+			#[cfg(feature = "rsa")]
+			SigningKeyError::Encoding(error) => Some(error),
+		}
+	}
+}
@@ -0,0 +1,188 @@
+//! A runtime-independent timer.
+//!
+//! `std::time::Instant` panics on `wasm32-unknown-unknown`, so the crate uses
+//! the [`Instant`] from this module, which reads the browser's clock there.
+
+use std::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+use std::{
+	collections::BTreeMap,
+	sync::{Condvar, Mutex, MutexGuard, OnceLock, PoisonError},
+	task::Waker,
+	thread,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::Instant;
+
+/// A future that completes at a given instant. The wait is timed by a timer
+/// thread shared by all sleeps, so waiting works with any async runtime.
+/// Dropping the future cancels the wait.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) struct Sleep {
+	until: Instant,
+	/// The key of this sleep in the timer, once it was polled.
+	entry: Option<(Instant, u64)>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Sleep {
+	pub(crate) fn until(until: Instant) -> Self {
+		Self { until, entry: None }
+	}
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Future for Sleep {
+	type Output = ();
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+		if Instant::now() >= self.until {
+			if let Some(entry) = self.entry.take() {
+				Timer::get().lock().wakers.remove(&entry);
+			}
+			return Poll::Ready(());
+		}
+
+		let timer = Timer::get();
+		let mut state = timer.lock();
+		let entry = match self.entry {
+			Some(entry) => entry,
+			None => {
+				state.next_id += 1;
+				(self.until, state.next_id)
+			}
+		};
+		state.wakers.insert(entry, cx.waker().clone());
+		let earliest = state
+			.wakers
+			.first_key_value()
+			.is_some_and(|(first, _)| *first == entry);
+		drop(state);
+		if earliest && self.entry.is_none() {
+			timer.changed.notify_one();
+		}
+		self.entry = Some(entry);
+		Poll::Pending
+	}
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for Sleep {
+	fn drop(&mut self) {
+		if let Some(entry) = self.entry.take() {
+			Timer::get().lock().wakers.remove(&entry);
+		}
+	}
+}
+
+/// The timer thread that wakes every [`Sleep`] once its instant has passed.
+#[cfg(not(target_arch = "wasm32"))]
+struct Timer {
+	state: Mutex<TimerState>,
+	/// Notified when a sleep that ends before all others is added.
+	changed: Condvar,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Default)]
+struct TimerState {
+	/// Wakers of the pending sleeps by end and a unique ID, so the sleep that
+	/// ends first comes first.
+	wakers: BTreeMap<(Instant, u64), Waker>,
+	next_id: u64,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Timer {
+	/// Returns the timer, starting its thread on first use.
+	fn get() -> &'static Timer {
+		static TIMER: OnceLock<Timer> = OnceLock::new();
+		let mut created = false;
+		let timer = TIMER.get_or_init(|| {
+			created = true;
+			Timer {
+				state: Mutex::default(),
+				changed: Condvar::new(),
+			}
+		});
+		if created {
+			thread::Builder::new()
+				.name("bunqers-timer".into())
+				.spawn(|| timer.run())
+				.expect("Failed to start timer thread");
+		}
+		timer
+	}
+
+	fn lock(&self) -> MutexGuard<'_, TimerState> {
+		self.state.lock().unwrap_or_else(PoisonError::into_inner)
+	}
+
+	fn run(&self) {
+		let mut state = self.lock();
+		loop {
+			let now = Instant::now();
+			let mut due = Vec::new();
+			while let Some(entry) = state.wakers.first_entry() {
+				if entry.key().0 > now {
+					break;
+				}
+				due.push(entry.remove());
+			}
+			if !due.is_empty() {
+				// Wake without holding the lock, in case a waker polls its
+				// sleep right away.
+				drop(state);
+				due.into_iter().for_each(Waker::wake);
+				state = self.lock();
+				continue;
+			}
+
+			state = match state.wakers.first_key_value() {
+				Some(((until, _), _)) => {
+					let timeout = *until - now;
+					self.changed
+						.wait_timeout(state, timeout)
+						.unwrap_or_else(PoisonError::into_inner)
+						.0
+				}
+				None => self
+					.changed
+					.wait(state)
+					.unwrap_or_else(PoisonError::into_inner),
+			};
+		}
+	}
+}
+
+/// A future that completes at a given instant. Threads are not available on
+/// `wasm32`, so the wait is timed with JavaScript's `setTimeout` instead.
+#[cfg(target_arch = "wasm32")]
+pub(crate) struct Sleep(gloo_timers::future::TimeoutFuture);
+
+#[cfg(target_arch = "wasm32")]
+impl Sleep {
+	pub(crate) fn until(until: Instant) -> Self {
+		let millis = until.saturating_duration_since(Instant::now()).as_millis();
+		Self(gloo_timers::future::TimeoutFuture::new(
+			u32::try_from(millis).unwrap_or(u32::MAX),
+		))
+	}
+}
+
+#[cfg(target_arch = "wasm32")]
+impl Future for Sleep {
+	type Output = ();
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+		Pin::new(&mut self.0).poll(cx)
+	}
+}
@@ -0,0 +1,166 @@
+//! Client-side checks of request fields.
+//!
+//! Bunq rejects malformed input only after the request has counted against
+//! the rate limit. The builders and endpoints that take free-form input run
+//! these checks first and return a [`ValidationError`] instead of sending the
+//! request.
+//!
+//! ```
+//! use bunqers::{
+//!     types::{Amount, BunqMeTabBuilder, Currency},
+//!     validation::ValidationError,
+//! };
+//! use rust_decimal::Decimal;
+//!
+//! let result = BunqMeTabBuilder::new(Amount::new(Decimal::new(12345, 3), Currency::Eur), "Dinner")
+//!     .build();
+//! assert!(matches!(result, Err(ValidationError::AmountPrecision(_))));
+//! ```
+
+use std::{error::Error, fmt};
+
+use reqwest::{Url, header::HeaderValue};
+use rust_decimal::Decimal;
+
+use crate::types::{Amount, CurrencyMismatch};
+
+/// Longest device description, in characters, this library sends to Bunq.
+pub const MAX_DEVICE_DESCRIPTION_LEN: usize = 255;
+
+/// Longest description of a bunq.me payment request, in characters.
+pub const MAX_BUNQME_DESCRIPTION_LEN: usize = 9000;
+
+/// Most decimal places Bunq accepts in an amount.
+pub const MAX_AMOUNT_DECIMALS: u32 = 2;
+
+/// A request field that Bunq would reject.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationError {
+	/// The device description is empty.
+	EmptyDeviceDescription,
+	/// The device description is longer than
+	/// [`MAX_DEVICE_DESCRIPTION_LEN`] characters. Holds its length.
+	DeviceDescriptionTooLong(usize),
+	/// The description of a bunq.me payment request is longer than
+	/// [`MAX_BUNQME_DESCRIPTION_LEN`] characters. Holds its length.
+	DescriptionTooLong(usize),
+	/// The amount has more than [`MAX_AMOUNT_DECIMALS`] decimal places.
+	AmountPrecision(Decimal),
+	/// The amount is zero or negative.
+	NonPositiveAmount(Decimal),
+	/// The redirect URL is not an absolute `http(s)` URL.
+	InvalidRedirectUrl(String),
+	/// The amount is in a different currency than the monetary account.
+	CurrencyMismatch(CurrencyMismatch),
+	/// A value cannot be sent in an HTTP header, e.g. because it contains a
+	/// line break. Holds the value.
+	InvalidHeaderValue(String),
+}
+
+impl fmt::Display for ValidationError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			ValidationError::EmptyDeviceDescription => write!(f, "device description is empty"),
+			ValidationError::DeviceDescriptionTooLong(length) => write!(
+				f,
+				"device description has {length} characters, at most {MAX_DEVICE_DESCRIPTION_LEN} are allowed"
+			),
+			ValidationError::DescriptionTooLong(length) => write!(
+				f,
+				"description has {length} characters, at most {MAX_BUNQME_DESCRIPTION_LEN} are allowed"
+			),
+			ValidationError::AmountPrecision(value) => write!(
+				f,
+				"amount {value} has more than {MAX_AMOUNT_DECIMALS} decimal places"
+			),
+			ValidationError::NonPositiveAmount(value) => {
+				write!(f, "amount {value} is not positive")
+			}
+			ValidationError::InvalidRedirectUrl(url) => write!(f, "invalid redirect URL: {url}"),
+			ValidationError::CurrencyMismatch(error) => write!(f, "{error}"),
+			ValidationError::InvalidHeaderValue(value) => {
+				write!(f, "{value:?} cannot be sent in an HTTP header")
+			}
+		}
+	}
+}
+
+impl Error for ValidationError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			ValidationError::CurrencyMismatch(error) => Some(error),
+			_ => None,
+		}
+	}
+}
+
+impl From<CurrencyMismatch> for ValidationError {
+	fn from(error: CurrencyMismatch) -> Self {
+		ValidationError::CurrencyMismatch(error)
+	}
+}
+
+/// Checks that `description` can be used to register a device.
+pub fn validate_device_description(description: &str) -> Result<(), ValidationError> {
+	let length = description.chars().count();
+	if description.trim().is_empty() {
+		Err(ValidationError::EmptyDeviceDescription)
+	} else if length > MAX_DEVICE_DESCRIPTION_LEN {
+		Err(ValidationError::DeviceDescriptionTooLong(length))
+	} else {
+		Ok(())
+	}
+}
+
+/// Checks that `description` fits a bunq.me payment request.
+pub fn validate_bunqme_description(description: &str) -> Result<(), ValidationError> {
+	let length = description.chars().count();
+	if length > MAX_BUNQME_DESCRIPTION_LEN {
+		Err(ValidationError::DescriptionTooLong(length))
+	} else {
+		Ok(())
+	}
+}
+
+/// Checks that `amount` is positive and has at most two decimal places.
+///
+/// ```
+/// use bunqers::{types::{Amount, Currency}, validation::validate_amount};
+/// use rust_decimal::Decimal;
+///
+/// assert!(validate_amount(&Amount::new(Decimal::new(12500, 3), Currency::Eur)).is_ok());
+/// assert!(validate_amount(&Amount::new(Decimal::new(12505, 3), Currency::Eur)).is_err());
+/// ```
+pub fn validate_amount(amount: &Amount) -> Result<(), ValidationError> {
+	if amount.value <= Decimal::ZERO {
+		Err(ValidationError::NonPositiveAmount(amount.value))
+	} else if amount.value.normalize().scale() > MAX_AMOUNT_DECIMALS {
+		Err(ValidationError::AmountPrecision(amount.value))
+	} else {
+		Ok(())
+	}
+}
+
+/// Checks that `url` is an absolute `http(s)` URL.
+pub fn validate_redirect_url(url: &str) -> Result<(), ValidationError> {
+	match Url::parse(url) {
+		Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => Ok(()),
+		_ => Err(ValidationError::InvalidRedirectUrl(url.to_string())),
+	}
+}
+
+/// Checks that `value` can be sent as the value of an HTTP header.
+///
+/// ```
+/// use bunqers::validation::validate_header_value;
+///
+/// assert!(validate_header_value("nl_NL").is_ok());
+/// assert!(validate_header_value("nl_NL\r\nX-Injected: 1").is_err());
+/// ```
+pub fn validate_header_value(value: &str) -> Result<(), ValidationError> {
+	match HeaderValue::from_str(value) {
+		Ok(_) => Ok(()),
+		Err(_) => Err(ValidationError::InvalidHeaderValue(value.to_string())),
+	}
+}